@@ -10,4 +10,14 @@ pub const MARKETPLACE_STATE_SEED: &[u8] = b"marketplace_state";
 pub const PLATFORM_FEE: u64 = 250; // 2.5%
 pub const MIN_PROPERTY_PRICE: u64 = 1_000_000_000; // 1 SOL minimum
 pub const MIN_BID_INCREMENT: u64 = 100_000_000; // 0.1 SOL minimum increment
-pub const MAX_AUCTION_DURATION: u64 = 30 * 24 * 60 * 60; // 30 days in seconds
\ No newline at end of file
+pub const MAX_INCREMENT_TIERS: usize = 4;
+pub const MAX_AUCTION_DURATION: u64 = 30 * 24 * 60 * 60; // 30 days in seconds
+pub const MAX_AUCTION_EXTENSION: u64 = MAX_AUCTION_DURATION / 10; // 3 days of total anti-sniping extensions
+pub const MAX_APPROVED_MINTS: usize = 8; // SPL mints auctions can be priced in
+pub const RESERVE_REVEAL_WINDOW: u64 = 2 * 24 * 60 * 60; // 2 days for sellers to reveal a hidden reserve
+pub const MAX_ESCROW_DURATION: u64 = 90 * 24 * 60 * 60; // 90 days at most until an escrow's closing deadline
+pub const AUCTION_ESCROW_DURATION: u64 = 30 * 24 * 60 * 60; // 30 days to close escrows created by auctions
+pub const MAX_MILESTONES: usize = 8;
+pub const CRANK_REWARD_BPS: u64 = 1000; // 10% of the platform fee goes to the settlement crank
+pub const MAX_LOT_SIZE: usize = 10; // properties that can be bundled into a lot on top of the main one
+pub const CANCEL_PENALTY_BPS: u64 = 500; // 5% of the leading bid to cancel an auction with bids
\ No newline at end of file
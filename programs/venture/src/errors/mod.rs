@@ -76,4 +76,130 @@ pub enum ErrorCode {
     
     #[msg("Invalid escrow amount")]
     InvalidEscrowAmount,
+    
+    #[msg("Previous highest bidder accounts missing or invalid")]
+    InvalidPreviousBidder,
+    
+    #[msg("Bid is not refundable")]
+    BidNotRefundable,
+    
+    #[msg("Bid already refunded")]
+    BidAlreadyRefunded,
+    
+    #[msg("Bidder account does not match the auction")]
+    InvalidBidderAccount,
+    
+    #[msg("Invalid auction type")]
+    InvalidAuctionType,
+    
+    #[msg("Bid commitment missing or does not match the revealed bid")]
+    InvalidBidCommitment,
+    
+    #[msg("Auction is not in its reveal phase")]
+    NotInRevealPhase,
+    
+    #[msg("Bid already revealed")]
+    BidAlreadyRevealed,
+    
+    #[msg("Sealed bid was never revealed")]
+    BidNotRevealed,
+    
+    #[msg("Revealed amount exceeds the bid deposit")]
+    InsufficientBidDeposit,
+    
+    #[msg("Buy-now is not available for this auction")]
+    BuyNowNotAvailable,
+    
+    #[msg("Auction can no longer be cancelled")]
+    CannotCancelAuction,
+    
+    #[msg("Invalid bid increment rule")]
+    InvalidBidIncrement,
+    
+    #[msg("Auction has not started yet")]
+    AuctionNotStarted,
+    
+    #[msg("Bidder is not on the auction allowlist")]
+    BidderNotAllowlisted,
+    
+    #[msg("Proxy bids are not allowed on this auction")]
+    ProxyBidNotAllowed,
+    
+    #[msg("Auction is not awaiting payment")]
+    NotAwaitingPayment,
+    
+    #[msg("Payment deadline has passed")]
+    PaymentDeadlinePassed,
+    
+    #[msg("Payment deadline has not passed yet")]
+    PaymentDeadlineNotPassed,
+    
+    #[msg("No eligible runner-up to offer the property to")]
+    NoEligibleRunnerUp,
+    
+    #[msg("Property already has an active auction or escrow")]
+    PropertyHasActiveSale,
+    
+    #[msg("Invalid lot of properties")]
+    InvalidLot,
+    
+    #[msg("Lot property accounts do not match the auction")]
+    InvalidLotAccounts,
+    
+    #[msg("Payment mint is not approved by the marketplace")]
+    PaymentMintNotApproved,
+    
+    #[msg("Too many approved payment mints")]
+    TooManyApprovedMints,
+    
+    #[msg("Invalid payment token account")]
+    InvalidPaymentAccount,
+    
+    #[msg("Reserve price does not match its commitment")]
+    InvalidReserveCommitment,
+    
+    #[msg("Hidden reserve price has not been revealed yet")]
+    ReserveNotRevealed,
+    
+    #[msg("Invalid relisting fallback")]
+    InvalidRelistFallback,
+    
+    #[msg("Invalid escrow closing deadline")]
+    InvalidEscrowDeadline,
+    
+    #[msg("Escrow closing deadline has passed")]
+    EscrowExpired,
+    
+    #[msg("Escrow closing deadline has not passed yet")]
+    EscrowNotExpired,
+    
+    #[msg("Invalid escrow milestones")]
+    InvalidMilestones,
+    
+    #[msg("Milestone is not the next one to release")]
+    MilestoneNotReleasable,
+    
+    #[msg("Invalid escrow arbiter")]
+    InvalidArbiter,
+    
+    #[msg("Escrow is under dispute")]
+    EscrowDisputed,
+    
+    #[msg("Escrow is not under dispute")]
+    EscrowNotDisputed,
+    
+    #[msg("Only the escrow arbiter can resolve a dispute")]
+    NotEscrowArbiter,
+    
+    #[msg("Invalid dispute ruling")]
+    InvalidDisputeRuling,
+    
+    #[msg("Escrow release has not been approved by the counterparty")]
+    EscrowNotApproved,
+    
+    #[msg("Token vault is already in use")]
+    InvalidTokenVault,
+    
+    #[msg("Escrow account passed for an auction that does not settle through an escrow")]
+    UnexpectedEscrowAccount,
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::constants::ESCROW_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{Escrow, EscrowStatus};
+
+pub fn approve_escrow(
+    ctx: Context<ApproveEscrowCtx>,
+    escrow_id: u64,
+) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let party = &ctx.accounts.party;
+
+    // Validations
+    if escrow.escrow_id != escrow_id {
+        return Err(ErrorCode::EscrowNotFound.into());
+    }
+    if escrow.is_completed {
+        return Err(ErrorCode::EscrowAlreadyCompleted.into());
+    }
+    if escrow.status == EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+
+    // The buyer approves paying the seller, the seller approves refunding the buyer
+    if party.key() == escrow.buyer {
+        // Approving the release is only meaningful once the full price is deposited
+        if escrow.status != EscrowStatus::Funded {
+            return Err(ErrorCode::InsufficientEscrowBalance.into());
+        }
+        escrow.buyer_approved = true;
+        msg!("Buyer approved releasing escrow {} to the seller", escrow_id);
+    } else if party.key() == escrow.seller {
+        escrow.seller_approved = true;
+        msg!("Seller approved refunding escrow {} to the buyer", escrow_id);
+    } else {
+        return Err(ErrorCode::NotAuthorizedToReleaseEscrow.into());
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct ApproveEscrowCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            escrow_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    pub party: Signer<'info>,
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{AUCTION_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::end_auction::release_auction_nft;
+use crate::states::{Auction, Property, MarketplaceState};
+
+pub fn buy_dutch(
+    ctx: Context<BuyDutchCtx>,
+    auction_id: u64,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let property = &mut ctx.accounts.property;
+    let state = &ctx.accounts.marketplace_state;
+    let buyer = &ctx.accounts.buyer;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // Validations
+    if auction.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if auction.auction_type != 2 {
+        return Err(ErrorCode::InvalidAuctionType.into());
+    }
+    if auction.is_ended || now > auction.end_time {
+        return Err(ErrorCode::AuctionAlreadyEnded.into());
+    }
+    if now < auction.start_time {
+        return Err(ErrorCode::AuctionNotStarted.into());
+    }
+    if auction.seller == buyer.key() {
+        return Err(ErrorCode::CannotBidOnOwnAuction.into());
+    }
+    if !auction.is_allowlisted(&buyer.key(), &allowlist_proof) {
+        return Err(ErrorCode::BidderNotAllowlisted.into());
+    }
+
+    let sale_price = auction.dutch_price(now);
+    let platform_fee = (sale_price * state.platform_fee) / 10000;
+    let seller_amount = sale_price - platform_fee;
+
+    // Transfer payment
+    let transfer_to_seller = system_instruction::transfer(
+        &buyer.key(),
+        &ctx.accounts.seller.key(),
+        seller_amount,
+    );
+    let transfer_to_platform = system_instruction::transfer(
+        &buyer.key(),
+        &state.platform_treasury,
+        platform_fee,
+    );
+
+    invoke(
+        &transfer_to_seller,
+        &[
+            buyer.to_account_info(),
+            ctx.accounts.seller.to_account_info(),
+        ],
+    )?;
+
+    invoke(
+        &transfer_to_platform,
+        &[
+            buyer.to_account_info(),
+            ctx.accounts.platform_treasury.to_account_info(),
+        ],
+    )?;
+
+    // Transfer NFT from custody to buyer
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_SEED,
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction],
+    ]];
+
+    release_auction_nft(
+        auction,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &ctx.accounts.buyer_token_account.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // Update auction state
+    auction.current_bid = sale_price;
+    auction.highest_bidder = Some(buyer.key());
+    auction.winner = Some(buyer.key());
+    auction.is_ended = true;
+
+    // Update property ownership
+    property.owner = buyer.key();
+    property.is_listed = false;
+    property.list_price = 0;
+    property.active_auction_id = None;
+
+    msg!("Dutch auction {} won by {} for {} lamports", auction_id, buyer.key(), sale_price);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct BuyDutchCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            auction.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Auction-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = auction
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Seller receiving the sale proceeds, validated against the auction
+    #[account(
+        mut,
+        address = auction.seller
+    )]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform treasury
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
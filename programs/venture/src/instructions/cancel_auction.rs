@@ -0,0 +1,249 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::{AUCTION_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::end_auction::{close_bid_vault, pay_from_auction, payment_recipient, release_auction_nft, release_lot_properties};
+use crate::states::{Auction, Bid, Property, MarketplaceState};
+
+pub fn cancel_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelAuctionCtx<'info>>,
+    auction_id: u64,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let property = &mut ctx.accounts.property;
+    let state = &ctx.accounts.marketplace_state;
+    let seller = &ctx.accounts.seller;
+
+    // Validations
+    if auction.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if auction.seller != seller.key() {
+        return Err(ErrorCode::NotPropertyOwner.into());
+    }
+    // Once bidding is over the result stands and the auction settles through end_auction
+    if auction.is_ended || Clock::get()?.unix_timestamp as u64 >= auction.settle_time() {
+        return Err(ErrorCode::AuctionAlreadyEnded.into());
+    }
+    // Sealed deposits can't all be refunded here. Outbid deposits stay claimable
+    // since the auction account is kept
+    if auction.is_sealed() && auction.bid_count > 0 {
+        return Err(ErrorCode::CannotCancelAuction.into());
+    }
+
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_SEED,
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction],
+    ]];
+
+    // Refund the current highest bidder in full and charge the seller the penalty
+    if let Some(prev_bidder) = auction.highest_bidder {
+        let previous_bid = ctx
+            .accounts
+            .previous_bid
+            .as_mut()
+            .ok_or(ErrorCode::InvalidPreviousBidder)?;
+        let previous_bidder = ctx
+            .accounts
+            .previous_bidder
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPreviousBidder)?;
+
+        if previous_bid.auction_id != auction_id
+            || previous_bid.bidder != prev_bidder
+            || !previous_bid.is_winning
+            || previous_bidder.key() != prev_bidder
+        {
+            return Err(ErrorCode::InvalidPreviousBidder.into());
+        }
+
+        let recipient = payment_recipient(
+            auction,
+            &previous_bidder.to_account_info(),
+            ctx.accounts.previous_bidder_payment_account.as_deref(),
+        )?;
+        pay_from_auction(
+            auction,
+            &recipient,
+            ctx.accounts.bid_vault.as_deref(),
+            &ctx.accounts.token_program.to_account_info(),
+            signer_seeds,
+            previous_bid.deposit,
+        )?;
+        previous_bid.is_winning = false;
+        previous_bid.refunded = true;
+
+        // The penalty is paid in the auction's currency
+        let penalty = (auction.current_bid * state.cancel_penalty_bps) / 10000;
+        if penalty > 0 && auction.payment_mint.is_some() {
+            let cpi_accounts = Transfer {
+                from: payment_recipient(
+                    auction,
+                    &seller.to_account_info(),
+                    ctx.accounts.seller_payment_account.as_deref(),
+                )?,
+                to: payment_recipient(
+                    auction,
+                    &ctx.accounts.platform_treasury.to_account_info(),
+                    ctx.accounts.treasury_payment_account.as_deref(),
+                )?,
+                authority: seller.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::transfer(cpi_ctx, penalty)?;
+        } else if penalty > 0 {
+            let transfer_instruction = system_instruction::transfer(
+                &seller.key(),
+                &state.platform_treasury,
+                penalty,
+            );
+
+            invoke(
+                &transfer_instruction,
+                &[
+                    seller.to_account_info(),
+                    ctx.accounts.platform_treasury.to_account_info(),
+                ],
+            )?;
+        }
+
+        msg!("Refunded {} to {}, seller paid {} penalty", previous_bid.deposit, prev_bidder, penalty);
+    }
+
+    // Return NFT from custody to seller
+    release_auction_nft(
+        auction,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &ctx.accounts.seller_token_account.to_account_info(),
+        &seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // Close the bid vault as well once every bid is refunded
+    close_bid_vault(
+        auction,
+        ctx.accounts.bid_vault.as_mut(),
+        &seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // Clear the listing. The auction account stays open, flagged as cancelled, so bidders
+    // can still claim outbid deposits and close their bid accounts
+    property.is_listed = false;
+    property.list_price = 0;
+    property.listing_type = 0;
+    property.active_auction_id = None;
+
+    // Lot properties come back the same way, passed as `[property, nft_vault, seller_token_account]`
+    release_lot_properties(
+        auction,
+        ctx.remaining_accounts,
+        seller.key(),
+        &seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+        |property| {
+            property.is_listed = false;
+            property.list_price = 0;
+            property.listing_type = 0;
+        },
+    )?;
+
+    auction.is_cancelled = true;
+    auction.is_ended = true;
+
+    msg!("Auction {} cancelled", auction_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct CancelAuctionCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            auction.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Auction-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = auction
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Bid record of the current highest bidder, required once the auction has a bid
+    #[account(mut)]
+    pub previous_bid: Option<Account<'info, Bid>>,
+    
+    /// CHECK: Current highest bidder receiving the refund, validated against the auction
+    #[account(mut)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+    
+    /// Auction-owned token account holding the bids, required on token auctions so it can be closed
+    #[account(mut)]
+    pub bid_vault: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Payment mint token accounts of the previous bidder, seller and treasury,
+    /// used instead of their wallets on token auctions
+    #[account(mut)]
+    pub previous_bidder_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(mut)]
+    pub seller_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(mut)]
+    pub treasury_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// CHECK: Platform treasury receiving the cancellation penalty
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::constants::{AUCTION_SEED, BID_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::end_auction::{pay_from_auction, payment_recipient};
+use crate::states::{Auction, Bid};
+
+pub fn claim_bid_refund(
+    ctx: Context<ClaimBidRefundCtx>,
+    auction_id: u64,
+    _bid_index: u64,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let bid = &mut ctx.accounts.bid;
+    let bidder = &ctx.accounts.bidder;
+
+    // Validations
+    if auction.auction_id != auction_id || bid.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    // Outbid English bids can be claimed right away, which also keeps them
+    // claimable once a failed auction is relisted for another round
+    if !auction.is_ended && (bid.is_winning || auction.is_sealed()) {
+        return Err(ErrorCode::AuctionNotEnded.into());
+    }
+    // A bond winner's record is still needed until the balance is paid or the bond forfeited
+    if bid.is_winning && auction.payment_deadline != 0 && !auction.balance_paid {
+        return Err(ErrorCode::BidNotRefundable.into());
+    }
+    // Unrevealed deposits are slashed to the seller, not refunded
+    if !bid.refunded && !bid.revealed {
+        return Err(ErrorCode::BidNotRevealed.into());
+    }
+
+    // Bids already paid back, or with nothing left to refund, only get their account
+    // closed so the bidder recovers the rent
+    let refund_amount = if bid.refunded { 0 } else { bid.refundable_amount(auction) };
+    if refund_amount == 0 {
+        bid.refunded = true;
+        msg!("Closed bid account of {}", bidder.key());
+        return Ok(());
+    }
+
+    // Return the escrowed deposit, the bid account rent goes back on close
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_SEED,
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction],
+    ]];
+    let recipient = payment_recipient(
+        auction,
+        &bidder.to_account_info(),
+        ctx.accounts.bidder_payment_account.as_deref(),
+    )?;
+    pay_from_auction(
+        auction,
+        &recipient,
+        ctx.accounts.bid_vault.as_deref(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+        refund_amount,
+    )?;
+    bid.refunded = true;
+    if !bid.is_winning && !auction.is_sealed() {
+        auction.pending_refunds -= refund_amount;
+    }
+
+    msg!("Refunded {} to bidder {}", refund_amount, bidder.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64, bid_index: u64)]
+pub struct ClaimBidRefundCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [
+            BID_SEED,
+            bidder.key().as_ref(),
+            auction_id.to_le_bytes().as_ref(),
+            bid_index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub bid: Account<'info, Bid>,
+    
+    /// Auction-owned token account holding the bids of a token auction
+    #[account(mut)]
+    pub bid_vault: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Bidder's token account in the payment mint, required on token auctions
+    #[account(mut)]
+    pub bidder_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -1,16 +1,36 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, AUCTION_SEED, PROPERTY_SEED, MAX_AUCTION_DURATION};
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken, Create};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, AUCTION_SEED, PROPERTY_SEED, MAX_AUCTION_DURATION, MAX_AUCTION_EXTENSION, MAX_LOT_SIZE, MIN_PROPERTY_PRICE};
 use crate::errors::ErrorCode;
-use crate::states::{Auction, Property, MarketplaceState};
+use crate::states::{Auction, AuctionConfig, Property, MarketplaceState, RelistFallback};
 
-pub fn create_auction(
-    ctx: Context<CreateAuctionCtx>,
+pub fn create_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateAuctionCtx<'info>>,
     property_id: u64,
-    starting_price: u64,
-    reserve_price: u64,
-    duration: u64,
+    config: AuctionConfig,
 ) -> Result<()> {
-    let property = &ctx.accounts.property;
+    let AuctionConfig {
+        starting_price,
+        reserve_price,
+        duration,
+        extension_window,
+        extension_duration,
+        auction_type,
+        reveal_duration,
+        price_step,
+        buy_now_price,
+        bid_increment,
+        start_time,
+        allowlist_root,
+        bond_bps,
+        payment_window,
+        escrow_conditions,
+        lot_property_ids,
+        reserve_commitment,
+        relist_fallback,
+    } = config;
+    let property = &mut ctx.accounts.property;
     let auction = &mut ctx.accounts.auction;
     let state = &mut ctx.accounts.marketplace_state;
     let clock = Clock::get()?;
@@ -22,14 +42,132 @@ pub fn create_auction(
     if property.owner != ctx.accounts.seller.key() {
         return Err(ErrorCode::NotPropertyOwner.into());
     }
-    if !property.is_listed || property.listing_type != 1 {
+    if property.active_auction_id.is_some() || property.active_escrow_id.is_some() {
+        return Err(ErrorCode::PropertyHasActiveSale.into());
+    }
+    if auction_type > 3 {
+        return Err(ErrorCode::InvalidAuctionType.into());
+    }
+    // Dutch auctions need a property listed as such, every other kind a regular auction listing
+    let dutch = auction_type == 2;
+    let expected_listing_type = if dutch { 2 } else { 1 };
+    if !property.is_listed || property.listing_type != expected_listing_type {
         return Err(ErrorCode::PropertyNotListed.into());
     }
     if duration > MAX_AUCTION_DURATION {
         return Err(ErrorCode::InvalidAuctionDuration.into());
     }
-    if starting_price == 0 || reserve_price < starting_price {
-        return Err(ErrorCode::InvalidPropertyPrice.into());
+
+    // Auctions start now unless scheduled ahead, at most MAX_AUCTION_DURATION in advance
+    let now = clock.unix_timestamp as u64;
+    let start_time = start_time.unwrap_or(now);
+    if start_time < now || start_time - now > MAX_AUCTION_DURATION {
+        return Err(ErrorCode::InvalidAuctionDuration.into());
+    }
+    if extension_window > MAX_AUCTION_EXTENSION || extension_duration > MAX_AUCTION_EXTENSION {
+        return Err(ErrorCode::InvalidAuctionDuration.into());
+    }
+    // Only sealed-bid auctions have a reveal phase
+    let sealed = auction_type == 1 || auction_type == 3;
+    if sealed != (reveal_duration > 0) || reveal_duration > MAX_AUCTION_DURATION {
+        return Err(ErrorCode::InvalidAuctionDuration.into());
+    }
+    // A hidden reserve is only committed to here and revealed once bidding is over
+    if reserve_commitment.is_some() && (dutch || reserve_price > 0) {
+        return Err(ErrorCode::InvalidReserveCommitment.into());
+    }
+    if dutch {
+        // The reserve is the floor the price decays to, and bids never extend a Dutch auction
+        if duration == 0 || extension_window > 0 || price_step > duration {
+            return Err(ErrorCode::InvalidAuctionDuration.into());
+        }
+        if reserve_price == 0 || reserve_price > starting_price {
+            return Err(ErrorCode::InvalidPropertyPrice.into());
+        }
+    } else {
+        if price_step > 0 {
+            return Err(ErrorCode::InvalidAuctionType.into());
+        }
+        if starting_price == 0 || (reserve_commitment.is_none() && reserve_price < starting_price) {
+            return Err(ErrorCode::InvalidPropertyPrice.into());
+        }
+    }
+    // Bid bonds are only offered on English auctions, with a deadline to pay the balance
+    if bond_bps > 0 {
+        if auction_type != 0 || bond_bps >= 10000 {
+            return Err(ErrorCode::InvalidAuctionType.into());
+        }
+        if payment_window == 0 || payment_window > MAX_AUCTION_DURATION {
+            return Err(ErrorCode::InvalidAuctionDuration.into());
+        }
+    } else if payment_window > 0 {
+        return Err(ErrorCode::InvalidAuctionDuration.into());
+    }
+    // Escrow settlement takes the full winning bid through end_auction, so it can't be combined
+    // with bonds or with Dutch and buy-now purchases that complete immediately
+    if let Some(conditions) = &escrow_conditions {
+        if conditions.len() > 256 {
+            return Err(ErrorCode::ConditionsTooLong.into());
+        }
+        if bond_bps > 0 || dutch || buy_now_price.is_some() {
+            return Err(ErrorCode::InvalidAuctionType.into());
+        }
+    }
+    // Token auctions are priced in an approved SPL mint and settle like a plain English auction
+    if let Some(payment_mint) = ctx.accounts.payment_mint.as_ref() {
+        if !state.approved_mints.contains(&payment_mint.key()) {
+            return Err(ErrorCode::PaymentMintNotApproved.into());
+        }
+        if auction_type != 0 || buy_now_price.is_some() || bond_bps > 0 || escrow_conditions.is_some() {
+            return Err(ErrorCode::InvalidAuctionType.into());
+        }
+    }
+    // Lots bundle further properties into a plain English auction
+    if !lot_property_ids.is_empty() {
+        if auction_type != 0 || buy_now_price.is_some() || bond_bps > 0 || escrow_conditions.is_some() {
+            return Err(ErrorCode::InvalidAuctionType.into());
+        }
+        let duplicate = lot_property_ids
+            .iter()
+            .enumerate()
+            .any(|(i, id)| *id == property_id || lot_property_ids[..i].contains(id));
+        if lot_property_ids.len() > MAX_LOT_SIZE || duplicate {
+            return Err(ErrorCode::InvalidLot.into());
+        }
+    }
+    // A failed auction can fall back to a direct sale or one more English round with a lower reserve
+    if relist_fallback != RelistFallback::None && !lot_property_ids.is_empty() {
+        return Err(ErrorCode::InvalidRelistFallback.into());
+    }
+    match relist_fallback {
+        RelistFallback::None => {}
+        RelistFallback::DirectSale { price } => {
+            if price < MIN_PROPERTY_PRICE {
+                return Err(ErrorCode::InvalidPropertyPrice.into());
+            }
+        }
+        RelistFallback::Reauction { reserve_price: fallback_reserve, duration: fallback_duration } => {
+            if auction_type != 0
+                || fallback_duration == 0
+                || fallback_duration > MAX_AUCTION_DURATION
+                || fallback_reserve < starting_price
+                || (reserve_commitment.is_none() && fallback_reserve >= reserve_price)
+            {
+                return Err(ErrorCode::InvalidRelistFallback.into());
+            }
+        }
+    }
+    if !bid_increment.is_valid() {
+        return Err(ErrorCode::InvalidBidIncrement.into());
+    }
+    // Buy-now is only offered on English auctions, above the reserve
+    if let Some(buy_now_price) = buy_now_price {
+        if auction_type != 0 {
+            return Err(ErrorCode::InvalidAuctionType.into());
+        }
+        if buy_now_price < reserve_price || buy_now_price <= starting_price {
+            return Err(ErrorCode::InvalidPropertyPrice.into());
+        }
     }
 
     // Update state
@@ -37,21 +175,173 @@ pub fn create_auction(
 
     // Initialize auction
     auction.auction_id = state.total_auctions;
+    property.active_auction_id = Some(auction.auction_id);
     auction.property_id = property_id;
+    auction.auction_type = auction_type;
     auction.seller = ctx.accounts.seller.key();
     auction.starting_price = starting_price;
     auction.reserve_price = reserve_price;
+    auction.reserve_commitment = reserve_commitment;
+    auction.relist_fallback = relist_fallback;
     auction.current_bid = 0;
+    auction.second_bid = 0;
     auction.highest_bidder = None;
     auction.bid_count = 0;
-    auction.start_time = clock.unix_timestamp as u64;
-    auction.end_time = clock.unix_timestamp as u64 + duration;
+    auction.pending_refunds = 0;
+    auction.start_time = start_time;
+    auction.end_time = start_time + duration;
+    auction.reveal_end_time = auction.end_time + reveal_duration;
     auction.is_ended = false;
+    auction.is_cancelled = false;
     auction.winner = None;
+    auction.extension_window = extension_window;
+    auction.extension_duration = extension_duration;
+    auction.total_extension = 0;
+    auction.price_step = price_step;
+    auction.buy_now_price = buy_now_price;
+    auction.bid_increment = bid_increment;
+    auction.allowlist_root = allowlist_root;
+    auction.bond_bps = bond_bps;
+    auction.payment_window = payment_window;
+    auction.payment_deadline = 0;
+    auction.balance_paid = false;
+    auction.bond_forfeited = false;
+    auction.runner_up = None;
+    auction.escrow_conditions = escrow_conditions;
+    auction.lot_property_ids = lot_property_ids;
+    auction.payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+
+    // Move the property NFT into program custody so settlement needs no seller signature
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.seller_token_account.to_account_info(),
+        to: ctx.accounts.nft_vault.to_account_info(),
+        authority: ctx.accounts.seller.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+
+    // Token auctions hold bids in the auction's associated token account for the payment mint
+    if let Some(payment_mint) = ctx.accounts.payment_mint.as_ref() {
+        let bid_vault = ctx
+            .accounts
+            .bid_vault
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPaymentAccount)?;
+        if bid_vault.key() != get_associated_token_address(&auction.key(), &payment_mint.key()) {
+            return Err(ErrorCode::InvalidPaymentAccount.into());
+        }
+
+        create_token_vault(
+            &ctx.accounts.seller.to_account_info(),
+            &bid_vault.to_account_info(),
+            &auction.to_account_info(),
+            &payment_mint.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
+    }
+
+    lock_lot_properties(&ctx)?;
 
     Ok(())
 }
 
+/// Takes custody of the other properties in a lot, passed as
+/// `[property, mint, seller_token_account, nft_vault]` groups in remaining accounts.
+/// Each vault is created here as the auction's associated token account.
+fn lock_lot_properties<'info>(ctx: &Context<'_, '_, 'info, 'info, CreateAuctionCtx<'info>>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let seller = &ctx.accounts.seller;
+
+    if ctx.remaining_accounts.len() != auction.lot_property_ids.len() * 4 {
+        return Err(ErrorCode::InvalidLotAccounts.into());
+    }
+
+    for (property_id, accounts) in auction.lot_property_ids.iter().zip(ctx.remaining_accounts.chunks(4)) {
+        let [property_info, mint_info, seller_token_info, vault_info] = accounts else {
+            return Err(ErrorCode::InvalidLotAccounts.into());
+        };
+        let mut property = Account::<Property>::try_from(property_info)?;
+        if property.property_id != *property_id {
+            return Err(ErrorCode::PropertyNotFound.into());
+        }
+        if property.owner != seller.key() {
+            return Err(ErrorCode::NotPropertyOwner.into());
+        }
+        if property.active_auction_id.is_some() || property.active_escrow_id.is_some() {
+            return Err(ErrorCode::PropertyHasActiveSale.into());
+        }
+        if !property.is_listed || property.listing_type != 1 {
+            return Err(ErrorCode::PropertyNotListed.into());
+        }
+        let seller_token_account = Account::<TokenAccount>::try_from(seller_token_info)?;
+        if mint_info.key() != property.mint
+            || vault_info.key() != get_associated_token_address(&auction.key(), &property.mint)
+            || seller_token_account.mint != property.mint
+            || seller_token_account.owner != seller.key()
+        {
+            return Err(ErrorCode::InvalidLotAccounts.into());
+        }
+
+        // Create the auction-owned vault and move the NFT into it
+        create_token_vault(
+            &seller.to_account_info(),
+            vault_info,
+            &auction.to_account_info(),
+            mint_info,
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
+
+        let cpi_accounts = Transfer {
+            from: seller_token_info.clone(),
+            to: vault_info.clone(),
+            authority: seller.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, 1)?;
+
+        property.active_auction_id = Some(auction.auction_id);
+        property.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Creates `vault` as the associated token account of `authority` for `mint`.
+/// Its address is predictable, so an account someone created ahead of time is
+/// accepted as long as it is still empty.
+pub(crate) fn create_token_vault<'info>(
+    payer: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let cpi_accounts = Create {
+        payer: payer.clone(),
+        associated_token: vault.clone(),
+        authority: authority.clone(),
+        mint: mint.clone(),
+        system_program: system_program.clone(),
+        token_program: token_program.clone(),
+    };
+    let cpi_ctx = CpiContext::new(associated_token_program.clone(), cpi_accounts);
+    associated_token::create_idempotent(cpi_ctx)?;
+
+    let vault = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?;
+    if vault.owner != authority.key() || vault.amount > 0 {
+        return Err(ErrorCode::InvalidTokenVault.into());
+    }
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(property_id: u64)]
 pub struct CreateAuctionCtx<'info> {
@@ -71,6 +361,7 @@ pub struct CreateAuctionCtx<'info> {
     pub auction: Account<'info, Auction>,
     
     #[account(
+        mut,
         seeds = [
             PROPERTY_SEED,
             property_id.to_le_bytes().as_ref()
@@ -79,8 +370,36 @@ pub struct CreateAuctionCtx<'info> {
     )]
     pub property: Account<'info, Property>,
     
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Auction-owned token account holding the NFT until settlement
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = auction
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Approved SPL mint the auction is priced in, left out for SOL auctions
+    pub payment_mint: Option<Box<Account<'info, Mint>>>,
+    
+    /// CHECK: Auction-owned token account for bids in the payment mint, created here
+    #[account(mut)]
+    pub bid_vault: Option<UncheckedAccount<'info>>,
+    
     #[account(mut)]
     pub seller: Signer<'info>,
     
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, ESCROW_SEED, PROPERTY_SEED};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, ESCROW_SEED, MAX_ESCROW_DURATION, MAX_MILESTONES, PROPERTY_SEED};
 use crate::errors::ErrorCode;
-use crate::states::{Escrow, Property, MarketplaceState};
+use crate::states::{Escrow, EscrowStatus, Milestone, Property, MarketplaceState};
 
 pub fn create_escrow(
     ctx: Context<CreateEscrowCtx>,
@@ -9,8 +11,10 @@ pub fn create_escrow(
     buyer: Pubkey,
     amount: u64,
     conditions: String,
+    closing_deadline: u64,
+    mut milestones: Vec<Milestone>,
 ) -> Result<()> {
-    let property = &ctx.accounts.property;
+    let property = &mut ctx.accounts.property;
     let escrow = &mut ctx.accounts.escrow;
     let state = &mut ctx.accounts.marketplace_state;
     let clock = Clock::get()?;
@@ -25,27 +29,71 @@ pub fn create_escrow(
     if !property.is_listed {
         return Err(ErrorCode::PropertyNotListed.into());
     }
+    if property.active_auction_id.is_some() || property.active_escrow_id.is_some() {
+        return Err(ErrorCode::PropertyHasActiveSale.into());
+    }
+    // Disputes need a neutral third party
+    let arbiter = ctx.accounts.arbiter.key();
+    if arbiter == buyer || arbiter == ctx.accounts.seller.key() {
+        return Err(ErrorCode::InvalidArbiter.into());
+    }
     if conditions.len() > 256 {
         return Err(ErrorCode::ConditionsTooLong.into());
     }
     if amount == 0 {
         return Err(ErrorCode::InvalidEscrowAmount.into());
     }
+    let now = clock.unix_timestamp as u64;
+    if closing_deadline <= now || closing_deadline - now > MAX_ESCROW_DURATION {
+        return Err(ErrorCode::InvalidEscrowDeadline.into());
+    }
+    // Milestones split the whole amount into ordered tranches due before the deadline
+    if !milestones.is_empty() {
+        let total = milestones.iter().try_fold(0u64, |total, milestone| total.checked_add(milestone.amount));
+        if milestones.len() > MAX_MILESTONES
+            || total != Some(amount)
+            || milestones.iter().any(|milestone| milestone.amount == 0 || milestone.due_date > closing_deadline)
+            || milestones.windows(2).any(|pair| pair[0].due_date > pair[1].due_date)
+        {
+            return Err(ErrorCode::InvalidMilestones.into());
+        }
+        for milestone in milestones.iter_mut() {
+            milestone.status = 0;
+        }
+    }
 
     // Update state
     state.total_escrows += 1;
 
     // Initialize escrow
     escrow.escrow_id = state.total_escrows;
+    property.active_escrow_id = Some(escrow.escrow_id);
     escrow.property_id = property_id;
     escrow.seller = ctx.accounts.seller.key();
     escrow.buyer = buyer;
     escrow.amount = amount;
     escrow.deposited_amount = 0;
     escrow.conditions = conditions;
-    escrow.created_at = clock.unix_timestamp as u64;
+    escrow.created_at = now;
+    escrow.closing_deadline = closing_deadline;
     escrow.is_completed = false;
     escrow.released_to_seller = false;
+    escrow.milestones = milestones;
+    escrow.status = EscrowStatus::Pending;
+    escrow.arbiter = arbiter;
+    escrow.buyer_approved = false;
+    escrow.seller_approved = false;
+    escrow.rent_payer = ctx.accounts.seller.key();
+
+    // Move the property NFT into program custody so the seller can't move it while the buyer pays
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.seller_token_account.to_account_info(),
+        to: ctx.accounts.nft_vault.to_account_info(),
+        authority: ctx.accounts.seller.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
 
     Ok(())
 }
@@ -69,6 +117,7 @@ pub struct CreateEscrowCtx<'info> {
     pub escrow: Account<'info, Escrow>,
     
     #[account(
+        mut,
         seeds = [
             PROPERTY_SEED,
             property_id.to_le_bytes().as_ref()
@@ -77,8 +126,32 @@ pub struct CreateEscrowCtx<'info> {
     )]
     pub property: Account<'info, Property>,
     
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Escrow-owned token account holding the NFT until release
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = escrow
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Neutral third party resolving disputes on this escrow
+    pub arbiter: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub seller: Signer<'info>,
     
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
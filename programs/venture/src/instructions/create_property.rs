@@ -62,6 +62,8 @@ pub fn create_property(
     property.is_listed = false;
     property.list_price = 0;
     property.listing_type = 0;
+    property.active_auction_id = None;
+    property.active_escrow_id = None;
 
     // Mint the NFT to the owner
     let mint_cpi = MintTo {
@@ -4,7 +4,7 @@ use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction;
 use crate::constants::ESCROW_SEED;
 use crate::errors::ErrorCode;
-use crate::states::Escrow;
+use crate::states::{Escrow, EscrowStatus};
 
 pub fn deposit_escrow(
     ctx: Context<DepositEscrowCtx>,
@@ -21,6 +21,12 @@ pub fn deposit_escrow(
     if escrow.is_completed {
         return Err(ErrorCode::EscrowAlreadyCompleted.into());
     }
+    if escrow.status == EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+    if Clock::get()?.unix_timestamp as u64 > escrow.closing_deadline {
+        return Err(ErrorCode::EscrowExpired.into());
+    }
     if amount == 0 {
         return Err(ErrorCode::InvalidEscrowAmount.into());
     }
@@ -47,6 +53,9 @@ pub fn deposit_escrow(
 
     // Update escrow balance
     escrow.deposited_amount += amount;
+    if escrow.deposited_amount >= escrow.amount {
+        escrow.status = EscrowStatus::Funded;
+    }
 
     msg!("Deposited {} lamports to escrow {}", amount, escrow_id);
 
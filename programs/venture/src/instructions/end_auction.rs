@@ -1,17 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer};
-use crate::constants::{AUCTION_SEED, PROPERTY_SEED};
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, AUCTION_SEED, ESCROW_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED, AUCTION_ESCROW_DURATION, RESERVE_REVEAL_WINDOW};
 use crate::errors::ErrorCode;
-use crate::states::{Auction, Property, MarketplaceState};
+use crate::instructions::create_auction::create_token_vault;
+use crate::states::{Auction, Bid, Escrow, EscrowStatus, Property, MarketplaceState, RelistFallback};
 
-pub fn end_auction(
-    ctx: Context<EndAuctionCtx>,
+pub fn end_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EndAuctionCtx<'info>>,
     auction_id: u64,
 ) -> Result<()> {
     let auction = &mut ctx.accounts.auction;
     let property = &mut ctx.accounts.property;
-    let state = &ctx.accounts.marketplace_state;
+    let state = &mut ctx.accounts.marketplace_state;
     let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
 
     // Validations
     if auction.auction_id != auction_id {
@@ -20,58 +23,496 @@ pub fn end_auction(
     if auction.is_ended {
         return Err(ErrorCode::AuctionAlreadyEnded.into());
     }
-    if (clock.unix_timestamp as u64) < auction.end_time {
+    // Sealed-bid auctions settle once the reveal phase is over
+    let sealed = auction.is_sealed();
+    if now < auction.settle_time() {
         return Err(ErrorCode::AuctionNotEnded.into());
     }
+    // A hidden reserve gets a window to be revealed, after which it counts as not met.
+    // Without bids there is nothing to wait for.
+    let reserve_hidden = auction.reserve_commitment.is_some();
+    if reserve_hidden
+        && auction.highest_bidder.is_some()
+        && now <= auction.settle_time() + RESERVE_REVEAL_WINDOW
+    {
+        return Err(ErrorCode::ReserveNotRevealed.into());
+    }
+    // The escrow account is created whenever it is passed, so it is only accepted when the
+    // auction actually settles into it. Otherwise it would take the next escrow's address.
+    let reserve_met = !reserve_hidden && auction.current_bid >= auction.reserve_price;
+    let settles_to_escrow = reserve_met
+        && auction.highest_bidder.is_some()
+        && auction.bond_bps == 0
+        && auction.escrow_conditions.is_some();
+    if ctx.accounts.escrow.is_some() && !settles_to_escrow {
+        return Err(ErrorCode::UnexpectedEscrowAccount.into());
+    }
 
     auction.is_ended = true;
 
+    // The auction PDA owns the NFT vault, so anyone can crank settlement
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_SEED,
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction],
+    ]];
+
+    // Lot auctions pass their other properties first in remaining accounts, sealed bids follow
+    let lot_len = auction.lot_property_ids.len() * 3;
+    if ctx.remaining_accounts.len() < lot_len {
+        return Err(ErrorCode::InvalidLotAccounts.into());
+    }
+    let (lot_accounts, bid_accounts) = ctx.remaining_accounts.split_at(lot_len);
+
     // Check if reserve price was met
-    if auction.current_bid >= auction.reserve_price {
+    if reserve_met {
         if let Some(winner) = auction.highest_bidder {
+            // Bond auctions hand the winner a deadline to pay the balance, the NFT stays in custody
+            // and the property stays tied to the auction until it is paid or forfeited
+            if auction.bond_bps > 0 {
+                auction.winner = Some(winner);
+                auction.payment_deadline = now + auction.payment_window;
+                msg!("Auction won by {}, balance due by {}", winner, auction.payment_deadline);
+                return Ok(());
+            }
+
             auction.winner = Some(winner);
 
-            // Calculate platform fee
-            let platform_fee = (auction.current_bid * state.platform_fee) / 10000;
-            let seller_amount = auction.current_bid - platform_fee;
-
-            // Transfer payment to seller (simplified)
-            **auction.to_account_info().try_borrow_mut_lamports()? -= auction.current_bid;
-            **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += seller_amount;
-            **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
-
-            // Transfer NFT to winner
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.seller_token_account.to_account_info(),
-                to: ctx.accounts.winner_token_account.to_account_info(),
-                authority: ctx.accounts.seller.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-            token::transfer(cpi_ctx, 1)?;
-
-            // Update property ownership
-            property.owner = winner;
-            property.is_listed = false;
-            msg!("Auction ended successfully. Winner: {}", winner);
+            // Vickrey winners pay the second-highest bid, or the reserve if that is higher.
+            // The rest of their deposit stays refundable through their bid record.
+            if auction.auction_type == 3 {
+                auction.current_bid = auction.second_bid.max(auction.reserve_price);
+            }
+
+            if let Some(conditions) = auction.escrow_conditions.clone() {
+                // Settle into an escrow funded with the winning bid, the sale completes through release_escrow
+                let escrow = ctx
+                    .accounts
+                    .escrow
+                    .as_mut()
+                    .ok_or(ErrorCode::EscrowNotFound)?;
+
+                state.total_escrows += 1;
+
+                escrow.escrow_id = state.total_escrows;
+                escrow.property_id = auction.property_id;
+                escrow.seller = auction.seller;
+                escrow.buyer = winner;
+                escrow.amount = auction.current_bid;
+                escrow.deposited_amount = auction.current_bid;
+                escrow.conditions = conditions;
+                escrow.created_at = now;
+                escrow.closing_deadline = now + AUCTION_ESCROW_DURATION;
+                escrow.is_completed = false;
+                escrow.released_to_seller = false;
+                escrow.milestones = Vec::new();
+                // Escrows created by auctions are funded in full and arbitrated by the marketplace admin
+                escrow.status = EscrowStatus::Funded;
+                escrow.arbiter = state.admin;
+                escrow.buyer_approved = false;
+                escrow.seller_approved = false;
+                // The cranker fronts the rent of the escrow and its NFT vault and gets it back on completion
+                escrow.rent_payer = ctx.accounts.cranker.key();
+                property.active_escrow_id = Some(escrow.escrow_id);
+
+                **auction.to_account_info().try_borrow_mut_lamports()? -= auction.current_bid;
+                **escrow.to_account_info().try_borrow_mut_lamports()? += auction.current_bid;
+
+                // The NFT moves from the auction's custody into the escrow's until it is released
+                let escrow_nft_vault = ctx
+                    .accounts
+                    .escrow_nft_vault
+                    .as_ref()
+                    .ok_or(ErrorCode::EscrowNotFound)?;
+                if escrow_nft_vault.key() != get_associated_token_address(&escrow.key(), &property.mint) {
+                    return Err(ErrorCode::EscrowNotFound.into());
+                }
+
+                create_token_vault(
+                    &ctx.accounts.cranker.to_account_info(),
+                    &escrow_nft_vault.to_account_info(),
+                    &escrow.to_account_info(),
+                    &ctx.accounts.mint.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.associated_token_program.to_account_info(),
+                )?;
+
+                release_auction_nft(
+                    auction,
+                    &ctx.accounts.nft_vault.to_account_info(),
+                    &escrow_nft_vault.to_account_info(),
+                    &ctx.accounts.seller.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    signer_seeds,
+                )?;
+
+                msg!("Auction won by {}, settling through escrow {}", winner, escrow.escrow_id);
+            } else {
+                let winner_token_account = ctx
+                    .accounts
+                    .winner_token_account
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidBidderAccount)?;
+                if winner_token_account.owner != winner {
+                    return Err(ErrorCode::InvalidBidderAccount.into());
+                }
+
+                // Calculate platform fee and the crank's share of it
+                let platform_fee = (auction.current_bid * state.platform_fee) / 10000;
+                let crank_reward = (platform_fee * state.crank_reward_bps) / 10000;
+                let seller_amount = auction.current_bid - platform_fee;
+
+                // Transfer payment to seller, treasury and crank
+                let seller_recipient = payment_recipient(
+                    auction,
+                    &ctx.accounts.seller.to_account_info(),
+                    ctx.accounts.seller_payment_account.as_deref(),
+                )?;
+                let treasury_recipient = payment_recipient(
+                    auction,
+                    &ctx.accounts.platform_treasury.to_account_info(),
+                    ctx.accounts.treasury_payment_account.as_deref(),
+                )?;
+                let cranker_recipient = payment_recipient(
+                    auction,
+                    &ctx.accounts.cranker.to_account_info(),
+                    ctx.accounts.cranker_payment_account.as_deref(),
+                )?;
+                let bid_vault = ctx.accounts.bid_vault.as_deref();
+                let token_program = ctx.accounts.token_program.to_account_info();
+                pay_from_auction(auction, &seller_recipient, bid_vault, &token_program, signer_seeds, seller_amount)?;
+                pay_from_auction(auction, &treasury_recipient, bid_vault, &token_program, signer_seeds, platform_fee - crank_reward)?;
+                pay_from_auction(auction, &cranker_recipient, bid_vault, &token_program, signer_seeds, crank_reward)?;
+
+                // Transfer NFT from custody to winner
+                release_auction_nft(
+                    auction,
+                    &ctx.accounts.nft_vault.to_account_info(),
+                    &winner_token_account.to_account_info(),
+                    &ctx.accounts.seller.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    signer_seeds,
+                )?;
+
+                // Update property ownership
+                property.owner = winner;
+                property.is_listed = false;
+                release_lot_properties(
+                    auction,
+                    lot_accounts,
+                    winner,
+                    &ctx.accounts.seller.to_account_info(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    signer_seeds,
+                    |property| {
+                        property.owner = winner;
+                        property.is_listed = false;
+                    },
+                )?;
+                msg!("Auction ended successfully. Winner: {}", winner);
+            }
         }
     } else {
         // Reserve price not met, refund highest bidder
+        let relist_fallback = auction.relist_fallback;
+        let reauction = matches!(relist_fallback, RelistFallback::Reauction { .. });
         if auction.current_bid > 0 {
-            if let Some(_highest_bidder) = auction.highest_bidder {
-                **auction.to_account_info().try_borrow_mut_lamports()? -= auction.current_bid;
-                **ctx.accounts.highest_bidder_account.to_account_info().try_borrow_mut_lamports()? += auction.current_bid;
+            if let Some(highest_bidder) = auction.highest_bidder {
+                if ctx.accounts.highest_bidder_account.key() != highest_bidder {
+                    return Err(ErrorCode::InvalidBidderAccount.into());
+                }
+                let mut refund_amount = auction.leading_escrow();
+
+                // Before the next round the leading bid is refunded in full and closed out,
+                // so it can't be claimed again against the new round's leader
+                if reauction {
+                    let highest_bid = ctx
+                        .accounts
+                        .highest_bid
+                        .as_mut()
+                        .ok_or(ErrorCode::InvalidPreviousBidder)?;
+                    if highest_bid.auction_id != auction_id
+                        || highest_bid.bidder != highest_bidder
+                        || !highest_bid.is_winning
+                    {
+                        return Err(ErrorCode::InvalidPreviousBidder.into());
+                    }
+                    refund_amount = highest_bid.deposit;
+                    highest_bid.is_winning = false;
+                    highest_bid.refunded = true;
+                }
+
+                let recipient = payment_recipient(
+                    auction,
+                    &ctx.accounts.highest_bidder_account.to_account_info(),
+                    ctx.accounts.highest_bidder_payment_account.as_deref(),
+                )?;
+                pay_from_auction(
+                    auction,
+                    &recipient,
+                    ctx.accounts.bid_vault.as_deref(),
+                    &ctx.accounts.token_program.to_account_info(),
+                    signer_seeds,
+                    refund_amount,
+                )?;
             }
         }
+
+        // Roll into a new round with the lowered reserve, the NFT stays in custody
+        if let RelistFallback::Reauction { reserve_price, duration } = relist_fallback {
+            auction.restart(now, reserve_price, duration);
+            msg!("Reserve not met, auction relisted until {} with reserve {}", auction.end_time, reserve_price);
+            return Ok(());
+        }
+
+        // Return NFT from custody to seller
+        release_auction_nft(
+            auction,
+            &ctx.accounts.nft_vault.to_account_info(),
+            &ctx.accounts.seller_token_account.to_account_info(),
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            signer_seeds,
+        )?;
+
+        let seller = auction.seller;
+        release_lot_properties(
+            auction,
+            lot_accounts,
+            seller,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            signer_seeds,
+            |_| {},
+        )?;
+
+        // Or go back to a direct-sale listing
+        if let RelistFallback::DirectSale { price } = relist_fallback {
+            property.is_listed = true;
+            property.list_price = price;
+            property.listing_type = 0;
+            msg!("Reserve not met, property relisted for direct sale at {}", price);
+        }
+
         msg!("Auction ended without meeting reserve price");
     }
 
+    property.active_auction_id = None;
+
+    // Close the bid vault of a token auction once it is empty, returning its rent to the seller
+    close_bid_vault(
+        auction,
+        ctx.accounts.bid_vault.as_mut(),
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    if sealed {
+        settle_sealed_bids(auction, &ctx.accounts.seller, bid_accounts)?;
+    }
+
+    Ok(())
+}
+
+/// Resolves where a payment to `wallet` goes: the wallet itself on SOL auctions,
+/// or its token account in the payment mint on token auctions.
+pub(crate) fn payment_recipient<'info>(
+    auction: &Auction,
+    wallet: &AccountInfo<'info>,
+    token_account: Option<&Account<'info, TokenAccount>>,
+) -> Result<AccountInfo<'info>> {
+    let Some(payment_mint) = auction.payment_mint else {
+        return Ok(wallet.clone());
+    };
+
+    let token_account = token_account.ok_or(ErrorCode::InvalidPaymentAccount)?;
+    if token_account.mint != payment_mint || token_account.owner != wallet.key() {
+        return Err(ErrorCode::InvalidPaymentAccount.into());
+    }
+    Ok(token_account.to_account_info())
+}
+
+/// Moves a property NFT out of one of the auction's vaults to `recipient` and closes
+/// the vault, returning its rent to `rent_destination`.
+pub(crate) fn release_auction_nft<'info>(
+    auction: &Account<'info, Auction>,
+    nft_vault: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    rent_destination: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = Transfer {
+        from: nft_vault.clone(),
+        to: recipient.clone(),
+        authority: auction.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, 1)?;
+
+    let cpi_accounts = CloseAccount {
+        account: nft_vault.clone(),
+        destination: rent_destination.clone(),
+        authority: auction.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token::close_account(cpi_ctx)
+}
+
+/// Checks that `bid_vault` is the auction's token account for its payment mint.
+pub(crate) fn auction_bid_vault<'info>(
+    auction: &Account<'info, Auction>,
+    bid_vault: Option<&Account<'info, TokenAccount>>,
+) -> Result<AccountInfo<'info>> {
+    let payment_mint = auction.payment_mint.ok_or(ErrorCode::InvalidPaymentAccount)?;
+    let bid_vault = bid_vault.ok_or(ErrorCode::InvalidPaymentAccount)?;
+    if bid_vault.key() != get_associated_token_address(&auction.key(), &payment_mint) {
+        return Err(ErrorCode::InvalidPaymentAccount.into());
+    }
+    Ok(bid_vault.to_account_info())
+}
+
+/// Pays `amount` out of the bids the auction holds: lamports on SOL auctions,
+/// or a transfer from the bid vault on token auctions.
+pub(crate) fn pay_from_auction<'info>(
+    auction: &Account<'info, Auction>,
+    recipient: &AccountInfo<'info>,
+    bid_vault: Option<&Account<'info, TokenAccount>>,
+    token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if auction.payment_mint.is_none() {
+        **auction.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+        return Ok(());
+    }
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let cpi_accounts = Transfer {
+        from: auction_bid_vault(auction, bid_vault)?,
+        to: recipient.clone(),
+        authority: auction.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Closes the bid vault of a token auction to the seller once no bids are left in it.
+/// Outbid deposits still waiting to be claimed keep it open.
+pub(crate) fn close_bid_vault<'info>(
+    auction: &Account<'info, Auction>,
+    bid_vault: Option<&mut Box<Account<'info, TokenAccount>>>,
+    seller: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if auction.payment_mint.is_none() {
+        return Ok(());
+    }
+    let bid_vault = bid_vault.ok_or(ErrorCode::InvalidPaymentAccount)?;
+    let bid_vault_info = auction_bid_vault(auction, Some(&**bid_vault))?;
+    bid_vault.reload()?;
+    if bid_vault.amount > 0 {
+        return Ok(());
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: bid_vault_info,
+        destination: seller.clone(),
+        authority: auction.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token::close_account(cpi_ctx)
+}
+
+/// Hands the other properties of a lot, passed as `[property, nft_vault, token_account]`
+/// groups, to the recipient's token accounts and closes their vaults to the seller.
+pub(crate) fn release_lot_properties<'info>(
+    auction: &Account<'info, Auction>,
+    lot_accounts: &'info [AccountInfo<'info>],
+    recipient: Pubkey,
+    seller: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    update: impl Fn(&mut Property),
+) -> Result<()> {
+    if lot_accounts.len() != auction.lot_property_ids.len() * 3 {
+        return Err(ErrorCode::InvalidLotAccounts.into());
+    }
+
+    for (property_id, accounts) in auction.lot_property_ids.iter().zip(lot_accounts.chunks(3)) {
+        let [property_info, vault_info, token_account_info] = accounts else {
+            return Err(ErrorCode::InvalidLotAccounts.into());
+        };
+        let mut property = Account::<Property>::try_from(property_info)?;
+        let token_account = Account::<TokenAccount>::try_from(token_account_info)?;
+        if property.property_id != *property_id
+            || vault_info.key() != get_associated_token_address(&auction.key(), &property.mint)
+            || token_account.mint != property.mint
+            || token_account.owner != recipient
+        {
+            return Err(ErrorCode::InvalidLotAccounts.into());
+        }
+
+        release_auction_nft(auction, vault_info, token_account_info, seller, token_program, signer_seeds)?;
+
+        property.active_auction_id = None;
+        update(&mut property);
+        property.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Settles sealed bids passed as `[bid, bidder]` pairs in remaining accounts.
+/// Revealed bids get their deposit back (the winner less the price paid) and
+/// bids that were never revealed are slashed to the seller.
+fn settle_sealed_bids<'info>(
+    auction: &Account<'info, Auction>,
+    seller: &AccountInfo<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    for accounts in remaining_accounts.chunks(2) {
+        let [bid_info, bidder_info] = accounts else {
+            return Err(ErrorCode::InvalidBidderAccount.into());
+        };
+        let mut bid = Account::<Bid>::try_from(bid_info)?;
+        if bid.auction_id != auction.auction_id || bid.bidder != bidder_info.key() {
+            return Err(ErrorCode::InvalidBidderAccount.into());
+        }
+        if bid.refunded {
+            continue;
+        }
+
+        let (amount, recipient) = if bid.revealed {
+            (bid.refundable_amount(auction), bidder_info)
+        } else {
+            (bid.deposit, seller)
+        };
+        **auction.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+
+        bid.refunded = true;
+        bid.exit(&crate::ID)?;
+    }
+
     Ok(())
 }
 
 #[derive(Accounts)]
 #[instruction(auction_id: u64)]
 pub struct EndAuctionCtx<'info> {
+    #[account(
+        mut,
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
     pub marketplace_state: Account<'info, MarketplaceState>,
     
     #[account(
@@ -94,24 +535,97 @@ pub struct EndAuctionCtx<'info> {
     )]
     pub property: Account<'info, Property>,
     
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Auction-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = auction
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Seller receiving the sale proceeds, validated against the auction
+    #[account(
+        mut,
+        address = auction.seller
+    )]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// Seller token account receiving the NFT back if the reserve is not met
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Winner token account receiving the NFT, required when the reserve is met
+    #[account(
+        mut,
+        token::mint = mint
+    )]
+    pub winner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// CHECK: Platform treasury account
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    /// CHECK: Highest bidder account for refund if needed, validated against the auction
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub highest_bidder_account: UncheckedAccount<'info>,
     
-    /// CHECK: This is a token account owned by the seller containing the NFT
+    /// Bid record of the highest bidder, required when a failed auction with bids is relisted
     #[account(mut)]
-    pub seller_token_account: UncheckedAccount<'info>,
+    pub highest_bid: Option<Account<'info, Bid>>,
     
-    /// CHECK: This is a token account owned by the winner to receive the NFT
+    /// Auction-owned token account holding the bids, required on token auctions and closed once empty
     #[account(mut)]
-    pub winner_token_account: UncheckedAccount<'info>,
+    pub bid_vault: Option<Box<Account<'info, TokenAccount>>>,
     
-    /// CHECK: Platform treasury account
+    /// Payment mint token accounts of the seller, treasury, cranker and highest bidder,
+    /// used instead of their wallets on token auctions
     #[account(mut)]
-    pub platform_treasury: UncheckedAccount<'info>,
+    pub seller_payment_account: Option<Box<Account<'info, TokenAccount>>>,
     
-    /// CHECK: Highest bidder account for refund if needed
     #[account(mut)]
-    pub highest_bidder_account: UncheckedAccount<'info>,
+    pub treasury_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(mut)]
+    pub cranker_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(mut)]
+    pub highest_bidder_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Escrow created for auctions that settle through one, funded with the winning bid
+    #[account(
+        init,
+        payer = cranker,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Escrow::INIT_SPACE,
+        seeds = [
+            ESCROW_SEED,
+            (marketplace_state.total_escrows + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub escrow: Option<Box<Account<'info, Escrow>>>,
+    
+    /// CHECK: Escrow-owned token account taking custody of the NFT, validated and created
+    /// in the instruction when the auction settles through an escrow
+    #[account(mut)]
+    pub escrow_nft_vault: Option<UncheckedAccount<'info>>,
+    
+    /// Anyone may settle the auction once it has ended. Lot auctions take their other
+    /// properties first in remaining accounts, sealed-bid auctions `[bid, bidder]` pairs
+    /// to refund or slash.
+    #[account(mut)]
+    pub cranker: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
-}
\ No newline at end of file
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{ESCROW_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::release_escrow::release_escrow_nft;
+use crate::states::{Escrow, EscrowStatus, Property};
+
+pub fn expire_escrow(
+    ctx: Context<ExpireEscrowCtx>,
+    escrow_id: u64,
+) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let property = &mut ctx.accounts.property;
+    let clock = Clock::get()?;
+
+    // Validations
+    if escrow.escrow_id != escrow_id {
+        return Err(ErrorCode::EscrowNotFound.into());
+    }
+    if escrow.is_completed {
+        return Err(ErrorCode::EscrowAlreadyCompleted.into());
+    }
+    // A disputed escrow is only settled by the arbiter, even past its deadline
+    if escrow.status == EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+    if clock.unix_timestamp as u64 <= escrow.closing_deadline {
+        return Err(ErrorCode::EscrowNotExpired.into());
+    }
+
+    // Refund everything deposited to the buyer, no seller signature needed
+    let refund_amount = escrow.deposited_amount;
+    **escrow.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
+    **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += refund_amount;
+
+    // Return NFT from custody to seller
+    release_escrow_nft(
+        escrow,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &ctx.accounts.seller_token_account.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.bumps.escrow,
+    )?;
+
+    escrow.is_completed = true;
+    escrow.released_to_seller = false;
+    escrow.deposited_amount = 0;
+    property.active_escrow_id = None;
+
+    msg!("Escrow {} expired, refunded {} lamports to buyer", escrow_id, refund_amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct ExpireEscrowCtx<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            ESCROW_SEED,
+            escrow_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            escrow.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    /// CHECK: Buyer receiving the refund, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.buyer
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// CHECK: Seller getting the NFT back, validated against the escrow
+    #[account(address = escrow.seller)]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// CHECK: Account that paid the escrow rent, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Escrow-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Seller token account receiving the NFT back
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Anyone may expire the escrow once its deadline has passed
+    pub caller: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
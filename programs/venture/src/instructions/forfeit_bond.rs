@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{AUCTION_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::end_auction::release_auction_nft;
+use crate::states::{Auction, Bid, Property, MarketplaceState};
+
+pub fn forfeit_bond(
+    ctx: Context<ForfeitBondCtx>,
+    auction_id: u64,
+    offer_to_runner_up: bool,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let state = &ctx.accounts.marketplace_state;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // Validations
+    if auction.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if auction.seller != ctx.accounts.seller.key() {
+        return Err(ErrorCode::NotPropertyOwner.into());
+    }
+    let Some(winner) = auction.winner else {
+        return Err(ErrorCode::NotAwaitingPayment.into());
+    };
+    if auction.bond_bps == 0 || !auction.is_ended || auction.balance_paid || auction.payment_deadline == 0 {
+        return Err(ErrorCode::NotAwaitingPayment.into());
+    }
+    if now <= auction.payment_deadline {
+        return Err(ErrorCode::PaymentDeadlineNotPassed.into());
+    }
+
+    // Forfeit the defaulting winner's bond to the seller and treasury
+    if !auction.bond_forfeited {
+        let winner_bid = ctx
+            .accounts
+            .winner_bid
+            .as_mut()
+            .ok_or(ErrorCode::InvalidBidderAccount)?;
+        if winner_bid.auction_id != auction_id || winner_bid.bidder != winner || !winner_bid.is_winning {
+            return Err(ErrorCode::InvalidBidderAccount.into());
+        }
+
+        let bond = winner_bid.deposit;
+        let platform_fee = (bond * state.platform_fee) / 10000;
+        **auction.to_account_info().try_borrow_mut_lamports()? -= bond;
+        **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += bond - platform_fee;
+        **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
+
+        winner_bid.is_winning = false;
+        winner_bid.refunded = true;
+        auction.bond_forfeited = true;
+
+        msg!("Bond of {} lamports forfeited by {}", bond, winner);
+    }
+
+    if offer_to_runner_up {
+        // The runner-up gets a fresh deadline to pay their full bid
+        let runner_up = auction
+            .runner_up
+            .filter(|runner_up| *runner_up != winner && auction.second_bid >= auction.reserve_price)
+            .ok_or(ErrorCode::NoEligibleRunnerUp)?;
+
+        auction.winner = Some(runner_up);
+        auction.highest_bidder = Some(runner_up);
+        auction.current_bid = auction.second_bid;
+        auction.runner_up = None;
+        auction.second_bid = 0;
+        auction.payment_deadline = now + auction.payment_window;
+
+        msg!("Property offered to runner-up {}, payment due by {}", runner_up, auction.payment_deadline);
+        return Ok(());
+    }
+
+    // Return NFT from custody to seller
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_SEED,
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction],
+    ]];
+
+    release_auction_nft(
+        auction,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &ctx.accounts.seller_token_account.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    auction.winner = None;
+    auction.payment_deadline = 0;
+    ctx.accounts.property.active_auction_id = None;
+
+    msg!("Auction {} closed without payment, property returned to seller", auction_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct ForfeitBondCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            auction.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Auction-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = auction
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// Winning bid record whose bond is forfeited, required until the bond is taken
+    #[account(mut)]
+    pub winner_bid: Option<Account<'info, Bid>>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    /// CHECK: Platform treasury
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
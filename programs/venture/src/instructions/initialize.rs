@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, MARKETPLACE_STATE_SEED};
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, CANCEL_PENALTY_BPS, CRANK_REWARD_BPS, MARKETPLACE_STATE_SEED, MIN_BID_INCREMENT};
 use crate::errors::ErrorCode;
 use crate::states::MarketplaceState;
 
@@ -18,6 +18,10 @@ pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
     state.platform_fee = 250; // 2.5%
     state.platform_treasury = admin.key();
     state.admin = admin.key();
+    state.crank_reward_bps = CRANK_REWARD_BPS;
+    state.cancel_penalty_bps = CANCEL_PENALTY_BPS;
+    state.min_bid_increment = MIN_BID_INCREMENT;
+    state.approved_mints = Vec::new();
 
     Ok(())
 }
@@ -24,7 +24,7 @@ pub fn list_property(
     if price < MIN_PROPERTY_PRICE {
         return Err(ErrorCode::InvalidPropertyPrice.into());
     }
-    if listing_type > 1 {
+    if listing_type > 2 {
         return Err(ErrorCode::InvalidListingType.into());
     }
 
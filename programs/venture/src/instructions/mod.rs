@@ -10,6 +10,20 @@ pub mod release_escrow;
 pub mod buy_direct;
 pub mod cancel_listing;
 pub mod update_platform_settings;
+pub mod claim_bid_refund;
+pub mod reveal_bid;
+pub mod buy_dutch;
+pub mod buy_now;
+pub mod cancel_auction;
+pub mod pay_balance;
+pub mod forfeit_bond;
+pub mod reveal_reserve;
+pub mod expire_escrow;
+pub mod release_milestone;
+pub mod approve_escrow;
+pub mod raise_dispute;
+pub mod resolve_dispute;
+pub mod slash_unrevealed_bid;
 
 pub use initialize::*;
 pub use create_property::*;
@@ -22,4 +36,18 @@ pub use deposit_escrow::*;
 pub use release_escrow::*;
 pub use buy_direct::*;
 pub use cancel_listing::*;
-pub use update_platform_settings::*;
\ No newline at end of file
+pub use update_platform_settings::*;
+pub use claim_bid_refund::*;
+pub use reveal_bid::*;
+pub use buy_dutch::*;
+pub use buy_now::*;
+pub use cancel_auction::*;
+pub use pay_balance::*;
+pub use forfeit_bond::*;
+pub use reveal_reserve::*;
+pub use expire_escrow::*;
+pub use release_milestone::*;
+pub use approve_escrow::*;
+pub use raise_dispute::*;
+pub use resolve_dispute::*;
+pub use slash_unrevealed_bid::*;
\ No newline at end of file
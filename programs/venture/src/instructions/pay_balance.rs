@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{AUCTION_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::end_auction::release_auction_nft;
+use crate::states::{Auction, Property, MarketplaceState};
+
+pub fn pay_balance(
+    ctx: Context<PayBalanceCtx>,
+    auction_id: u64,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let property = &mut ctx.accounts.property;
+    let state = &ctx.accounts.marketplace_state;
+    let winner = &ctx.accounts.winner;
+    let clock = Clock::get()?;
+
+    // Validations
+    if auction.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if auction.bond_bps == 0
+        || !auction.is_ended
+        || auction.balance_paid
+        || auction.payment_deadline == 0
+        || auction.winner != Some(winner.key())
+    {
+        return Err(ErrorCode::NotAwaitingPayment.into());
+    }
+    if clock.unix_timestamp as u64 > auction.payment_deadline {
+        return Err(ErrorCode::PaymentDeadlinePassed.into());
+    }
+
+    // The winner's bond already sits in the auction, unless it was forfeited
+    // and the property was offered to the runner-up
+    let held = if auction.bond_forfeited { 0 } else { auction.leading_escrow() };
+    let balance = auction.current_bid - held;
+
+    let transfer_instruction = system_instruction::transfer(
+        &winner.key(),
+        &auction.key(),
+        balance,
+    );
+
+    invoke(
+        &transfer_instruction,
+        &[
+            winner.to_account_info(),
+            auction.to_account_info(),
+        ],
+    )?;
+
+    // Calculate platform fee
+    let platform_fee = (auction.current_bid * state.platform_fee) / 10000;
+    let seller_amount = auction.current_bid - platform_fee;
+
+    // Transfer payment to seller and treasury
+    **auction.to_account_info().try_borrow_mut_lamports()? -= auction.current_bid;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += seller_amount;
+    **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
+
+    // Transfer NFT from custody to winner
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_SEED,
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction],
+    ]];
+
+    release_auction_nft(
+        auction,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &ctx.accounts.winner_token_account.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // Update property ownership
+    property.owner = winner.key();
+    property.is_listed = false;
+    property.list_price = 0;
+    property.active_auction_id = None;
+
+    auction.balance_paid = true;
+
+    msg!("Balance of {} lamports paid by {}", balance, winner.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct PayBalanceCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            auction.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Auction-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = auction
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub winner: Signer<'info>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = winner
+    )]
+    pub winner_token_account: Box<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Seller receiving the sale proceeds, validated against the auction
+    #[account(
+        mut,
+        address = auction.seller
+    )]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// CHECK: Platform treasury
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,53 +1,256 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction;
-use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, AUCTION_SEED, BID_SEED, MIN_BID_INCREMENT};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, AUCTION_SEED, BID_SEED, MARKETPLACE_STATE_SEED};
 use crate::errors::ErrorCode;
-use crate::states::{Auction, Bid};
+use crate::instructions::end_auction::{auction_bid_vault, pay_from_auction, payment_recipient};
+use crate::states::{Auction, Bid, MarketplaceState};
 
 pub fn place_bid(
     ctx: Context<PlaceBidCtx>,
     auction_id: u64,
     bid_amount: u64,
+    commitment: Option<[u8; 32]>,
+    max_bid: Option<u64>,
+    allowlist_proof: Vec<[u8; 32]>,
 ) -> Result<()> {
     let auction = &mut ctx.accounts.auction;
     let bid = &mut ctx.accounts.bid;
     let bidder = &ctx.accounts.bidder;
+    let state = &ctx.accounts.marketplace_state;
     let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
 
     // Validations
     if auction.auction_id != auction_id {
         return Err(ErrorCode::AuctionNotFound.into());
     }
-    if auction.is_ended || clock.unix_timestamp as u64 > auction.end_time {
+    if auction.is_ended || now > auction.end_time {
         return Err(ErrorCode::AuctionAlreadyEnded.into());
     }
+    if now < auction.start_time {
+        return Err(ErrorCode::AuctionNotStarted.into());
+    }
     if auction.seller == bidder.key() {
         return Err(ErrorCode::CannotBidOnOwnAuction.into());
     }
+    if !auction.is_allowlisted(&bidder.key(), &allowlist_proof) {
+        return Err(ErrorCode::BidderNotAllowlisted.into());
+    }
+    if auction.auction_type == 2 {
+        return Err(ErrorCode::InvalidAuctionType.into());
+    }
+
+    // Sealed bids carry a commitment and escrow a deposit instead of an open amount
+    let sealed = auction.is_sealed();
+    if sealed != commitment.is_some() {
+        return Err(ErrorCode::InvalidBidCommitment.into());
+    }
+    if max_bid.is_some() && (sealed || auction.bond_bps > 0) {
+        return Err(ErrorCode::ProxyBidNotAllowed.into());
+    }
 
-    let min_bid = if auction.current_bid == 0 {
+    let min_bid = if sealed || auction.current_bid == 0 {
         auction.starting_price
     } else {
-        auction.current_bid + MIN_BID_INCREMENT
+        auction.current_bid + auction.min_increment(auction.current_bid, state.min_bid_increment)
     };
 
-    if bid_amount < min_bid {
+    // Proxy bids escrow their ceiling and the program bids only what is needed to lead
+    let ceiling = max_bid.unwrap_or(bid_amount);
+    if bid_amount < min_bid || ceiling < bid_amount {
         return Err(ErrorCode::BidAmountTooLow.into());
     }
 
-    // If there's a previous highest bidder, mark their bid as not winning
+    auction.bid_count += 1;
+
+    // Initialize bid record
+    bid.auction_id = auction_id;
+    bid.bidder = bidder.key();
+    bid.timestamp = now;
+    bid.refunded = false;
+
+    if let Some(commitment) = commitment {
+        transfer_to_auction(
+            bidder,
+            auction,
+            ctx.accounts.bidder_payment_account.as_deref(),
+            ctx.accounts.bid_vault.as_deref(),
+            &ctx.accounts.token_program,
+            bid_amount,
+        )?;
+
+        // Amount stays hidden until reveal_bid
+        bid.amount = 0;
+        bid.max_amount = 0;
+        bid.deposit = bid_amount;
+        bid.commitment = commitment;
+        bid.revealed = false;
+        bid.is_winning = false;
+        return Ok(());
+    }
+
+    bid.commitment = [0; 32];
+    bid.revealed = true;
+
+    let mut price = bid_amount;
     if let Some(prev_bidder) = auction.highest_bidder {
-        // In a full implementation, you'd want to refund the previous bidder
-        // This would require storing bid accounts and handling refunds
-        msg!("Previous highest bidder: {}", prev_bidder);
+        let previous_bid = ctx
+            .accounts
+            .previous_bid
+            .as_mut()
+            .ok_or(ErrorCode::InvalidPreviousBidder)?;
+
+        if previous_bid.auction_id != auction_id
+            || previous_bid.bidder != prev_bidder
+            || !previous_bid.is_winning
+        {
+            return Err(ErrorCode::InvalidPreviousBidder.into());
+        }
+
+        // The leader bidding again only raises their own ceiling and tops up its deposit,
+        // the price and the runner-up stay where they are
+        if bidder.key() == prev_bidder {
+            if auction.bond_bps > 0 {
+                return Err(ErrorCode::ProxyBidNotAllowed.into());
+            }
+            if ceiling <= previous_bid.max_amount {
+                return Err(ErrorCode::BidAmountTooLow.into());
+            }
+            let top_up = auction.escrow_for(ceiling) - previous_bid.deposit;
+            transfer_to_auction(
+                bidder,
+                auction,
+                ctx.accounts.bidder_payment_account.as_deref(),
+                ctx.accounts.bid_vault.as_deref(),
+                &ctx.accounts.token_program,
+                top_up,
+            )?;
+            previous_bid.deposit += top_up;
+            previous_bid.max_amount = ceiling;
+
+            bid.amount = 0;
+            bid.max_amount = 0;
+            bid.deposit = 0;
+            bid.is_winning = false;
+            bid.refunded = true;
+
+            msg!("Leader {} raised their ceiling to {}", prev_bidder, ceiling);
+            return Ok(());
+        }
+
+        // The leader's proxy ceiling covers this bid, so the leader is raised
+        // just enough to stay ahead and the new bid loses without escrowing anything
+        let leader_ceiling = previous_bid.max_amount;
+        if ceiling <= leader_ceiling {
+            let leader_price = leader_ceiling
+                .min(ceiling + auction.min_increment(ceiling, state.min_bid_increment));
+            previous_bid.amount = leader_price;
+            auction.current_bid = leader_price;
+            auction.second_bid = ceiling;
+            auction.runner_up = Some(bidder.key());
+
+            bid.amount = ceiling;
+            bid.max_amount = ceiling;
+            bid.deposit = 0;
+            bid.is_winning = false;
+            bid.refunded = true;
+
+            auction.extend_for_bid(now);
+            msg!("Outbid by proxy of {}, current bid {}", prev_bidder, leader_price);
+            return Ok(());
+        }
+
+        // Bid only what is needed to beat the previous leader's ceiling
+        price = bid_amount.max(
+            ceiling.min(leader_ceiling + auction.min_increment(leader_ceiling, state.min_bid_increment)),
+        );
+
+        // Mark the previous bid as not winning and refund it now when the previous
+        // bidder's account is supplied; otherwise they claim it after the auction
+        previous_bid.is_winning = false;
+        if let Some(previous_bidder) = ctx.accounts.previous_bidder.as_ref() {
+            if previous_bidder.key() != prev_bidder {
+                return Err(ErrorCode::InvalidPreviousBidder.into());
+            }
+
+            let auction_id_bytes = auction_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                AUCTION_SEED,
+                auction_id_bytes.as_ref(),
+                &[ctx.bumps.auction],
+            ]];
+            let recipient = payment_recipient(
+                auction,
+                &previous_bidder.to_account_info(),
+                ctx.accounts.previous_bidder_payment_account.as_deref(),
+            )?;
+            pay_from_auction(
+                auction,
+                &recipient,
+                ctx.accounts.bid_vault.as_deref(),
+                &ctx.accounts.token_program.to_account_info(),
+                signer_seeds,
+                previous_bid.deposit,
+            )?;
+            previous_bid.refunded = true;
+
+            msg!("Refunded {} to previous highest bidder {}", previous_bid.deposit, prev_bidder);
+        } else {
+            auction.pending_refunds += previous_bid.deposit;
+        }
+        auction.second_bid = leader_ceiling;
+        auction.runner_up = Some(prev_bidder);
+    }
+
+    // Proxy bids escrow their whole ceiling, bond auctions only the bond
+    let deposit = auction.escrow_for(ceiling);
+    transfer_to_auction(
+        bidder,
+        auction,
+        ctx.accounts.bidder_payment_account.as_deref(),
+        ctx.accounts.bid_vault.as_deref(),
+        &ctx.accounts.token_program,
+        deposit,
+    )?;
+
+    bid.amount = price;
+    bid.max_amount = ceiling;
+    bid.deposit = deposit;
+    bid.is_winning = true;
+
+    // Update auction state
+    auction.current_bid = price;
+    auction.highest_bidder = Some(bidder.key());
+    auction.extend_for_bid(now);
+
+    Ok(())
+}
+
+// Transfer bid amount to auction escrow, into the bid vault on token auctions
+fn transfer_to_auction<'info>(
+    bidder: &Signer<'info>,
+    auction: &Account<'info, Auction>,
+    bidder_payment_account: Option<&Account<'info, TokenAccount>>,
+    bid_vault: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    if auction.payment_mint.is_some() {
+        let cpi_accounts = Transfer {
+            from: payment_recipient(auction, &bidder.to_account_info(), bidder_payment_account)?,
+            to: auction_bid_vault(auction, bid_vault)?,
+            authority: bidder.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+        return token::transfer(cpi_ctx, amount);
     }
 
-    // Transfer bid amount to auction escrow (simplified)
     let transfer_instruction = system_instruction::transfer(
         &bidder.key(),
         &auction.key(),
-        bid_amount,
+        amount,
     );
 
     invoke(
@@ -58,24 +261,18 @@ pub fn place_bid(
         ],
     )?;
 
-    // Update auction state
-    auction.current_bid = bid_amount;
-    auction.highest_bidder = Some(bidder.key());
-    auction.bid_count += 1;
-
-    // Initialize bid record
-    bid.auction_id = auction_id;
-    bid.bidder = bidder.key();
-    bid.amount = bid_amount;
-    bid.timestamp = clock.unix_timestamp as u64;
-    bid.is_winning = true;
-
     Ok(())
 }
 
 #[derive(Accounts)]
 #[instruction(auction_id: u64)]
 pub struct PlaceBidCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
     #[account(
         mut,
         seeds = [
@@ -100,8 +297,29 @@ pub struct PlaceBidCtx<'info> {
     )]
     pub bid: Account<'info, Bid>,
     
+    /// Bid record of the current highest bidder, required once the auction has a bid
+    #[account(mut)]
+    pub previous_bid: Option<Account<'info, Bid>>,
+    
+    /// CHECK: Current highest bidder receiving an immediate refund, validated against the auction
+    #[account(mut)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+    
+    /// Auction-owned token account holding the bids of a token auction
+    #[account(mut)]
+    pub bid_vault: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Bidder's token account in the payment mint, required on token auctions
+    #[account(mut)]
+    pub bidder_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Previous highest bidder's token account, receiving an immediate refund on token auctions
+    #[account(mut)]
+    pub previous_bidder_payment_account: Option<Box<Account<'info, TokenAccount>>>,
+    
     #[account(mut)]
     pub bidder: Signer<'info>,
     
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::constants::ESCROW_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{Escrow, EscrowStatus};
+
+pub fn raise_dispute(
+    ctx: Context<RaiseDisputeCtx>,
+    escrow_id: u64,
+) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let party = &ctx.accounts.party;
+
+    // Validations
+    if escrow.escrow_id != escrow_id {
+        return Err(ErrorCode::EscrowNotFound.into());
+    }
+    if escrow.is_completed {
+        return Err(ErrorCode::EscrowAlreadyCompleted.into());
+    }
+    if escrow.status == EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+    if escrow.deposited_amount == 0 {
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
+
+    // Only buyer or seller can dispute
+    if party.key() != escrow.buyer && party.key() != escrow.seller {
+        return Err(ErrorCode::NotAuthorizedToReleaseEscrow.into());
+    }
+
+    // Releases and deposits stay frozen until the arbiter rules
+    escrow.status = EscrowStatus::Disputed;
+
+    msg!("Escrow {} disputed by {}, awaiting arbiter {}", escrow_id, party.key(), escrow.arbiter);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct RaiseDisputeCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            escrow_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    pub party: Signer<'info>,
+}
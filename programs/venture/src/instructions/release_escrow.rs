@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
 use crate::constants::{ESCROW_SEED, PROPERTY_SEED};
 use crate::errors::ErrorCode;
-use crate::states::{Escrow, Property, MarketplaceState};
+use crate::states::{Escrow, EscrowStatus, Property, MarketplaceState};
 
 pub fn release_escrow(
     ctx: Context<ReleaseEscrowCtx>,
@@ -21,17 +21,37 @@ pub fn release_escrow(
     if escrow.is_completed {
         return Err(ErrorCode::EscrowAlreadyCompleted.into());
     }
+    if escrow.status == EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+    // Milestone escrows pay the seller tranche by tranche through release_milestone
+    if release_to_seller && !escrow.milestones.is_empty() {
+        return Err(ErrorCode::MilestoneNotReleasable.into());
+    }
+    // Past the deadline the escrow can only go back to the buyer
+    if release_to_seller && Clock::get()?.unix_timestamp as u64 > escrow.closing_deadline {
+        return Err(ErrorCode::EscrowExpired.into());
+    }
     if escrow.deposited_amount == 0 {
         return Err(ErrorCode::InsufficientEscrowBalance.into());
     }
+    // The NFT only leaves custody for the buyer once the full price is in the escrow
+    if release_to_seller && escrow.status != EscrowStatus::Funded {
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
 
-    // Only buyer, seller, or marketplace admin can release escrow
-    if authority.key() != escrow.buyer &&
-       authority.key() != escrow.seller &&
-       authority.key() != state.admin {
+    // Only buyer or seller can release escrow
+    if authority.key() != escrow.buyer && authority.key() != escrow.seller {
         return Err(ErrorCode::NotAuthorizedToReleaseEscrow.into());
     }
 
+    // Funds only move with the consent of the party giving them up: the buyer's to pay
+    // the seller, the seller's to refund the buyer. Anything contested goes through a dispute
+    let approved = if release_to_seller { escrow.buyer_approved } else { escrow.seller_approved };
+    if !approved {
+        return Err(ErrorCode::EscrowNotApproved.into());
+    }
+
     let release_amount = escrow.deposited_amount;
     let platform_fee = (release_amount * state.platform_fee) / 10000;
     let net_amount = release_amount - platform_fee;
@@ -42,15 +62,20 @@ pub fn release_escrow(
         **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += net_amount;
         **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
 
-        // Transfer NFT to buyer
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.seller_token_account.to_account_info(),
-            to: ctx.accounts.buyer_token_account.to_account_info(),
-            authority: ctx.accounts.seller.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, 1)?;
+        // Deliver NFT from custody to buyer
+        let buyer_token_account = ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .ok_or(ErrorCode::InvalidBidderAccount)?;
+        release_escrow_nft(
+            escrow,
+            &ctx.accounts.nft_vault.to_account_info(),
+            &buyer_token_account.to_account_info(),
+            &ctx.accounts.rent_payer.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.bumps.escrow,
+        )?;
 
         // Update property ownership
         property.owner = escrow.buyer;
@@ -61,16 +86,66 @@ pub fn release_escrow(
         // Release funds back to buyer (refund scenario)
         **escrow.to_account_info().try_borrow_mut_lamports()? -= release_amount;
         **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += release_amount;
+
+        // Return NFT from custody to seller
+        let seller_token_account = ctx
+            .accounts
+            .seller_token_account
+            .as_ref()
+            .ok_or(ErrorCode::InvalidBidderAccount)?;
+        release_escrow_nft(
+            escrow,
+            &ctx.accounts.nft_vault.to_account_info(),
+            &seller_token_account.to_account_info(),
+            &ctx.accounts.rent_payer.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.bumps.escrow,
+        )?;
         escrow.released_to_seller = false;
         msg!("Escrow refunded to buyer.");
     }
 
     escrow.is_completed = true;
     escrow.deposited_amount = 0;
+    property.active_escrow_id = None;
 
     Ok(())
 }
 
+/// Moves the property NFT out of the escrow's vault to `recipient` and closes the
+/// vault, returning its rent to the escrow's rent payer.
+pub(crate) fn release_escrow_nft<'info>(
+    escrow: &Account<'info, Escrow>,
+    nft_vault: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    rent_payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    escrow_bump: u8,
+) -> Result<()> {
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        ESCROW_SEED,
+        escrow_id_bytes.as_ref(),
+        &[escrow_bump],
+    ]];
+
+    let cpi_accounts = Transfer {
+        from: nft_vault.clone(),
+        to: recipient.clone(),
+        authority: escrow.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, 1)?;
+
+    let cpi_accounts = CloseAccount {
+        account: nft_vault.clone(),
+        destination: rent_payer.clone(),
+        authority: escrow.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer_seeds);
+    token::close_account(cpi_ctx)
+}
+
 #[derive(Accounts)]
 #[instruction(escrow_id: u64)]
 pub struct ReleaseEscrowCtx<'info> {
@@ -78,6 +153,7 @@ pub struct ReleaseEscrowCtx<'info> {
     
     #[account(
         mut,
+        close = rent_payer,
         seeds = [
             ESCROW_SEED,
             escrow_id.to_le_bytes().as_ref()
@@ -96,24 +172,59 @@ pub struct ReleaseEscrowCtx<'info> {
     )]
     pub property: Account<'info, Property>,
     
-    /// CHECK: Buyer account
-    #[account(mut)]
+    /// CHECK: Buyer account, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.buyer
+    )]
     pub buyer: UncheckedAccount<'info>,
     
-    /// CHECK: Seller account
-    #[account(mut)]
+    /// CHECK: Seller account, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.seller
+    )]
     pub seller: UncheckedAccount<'info>,
     
-    /// CHECK: This is a token account owned by the seller containing the NFT
-    #[account(mut)]
-    pub seller_token_account: UncheckedAccount<'info>,
+    /// CHECK: Account that paid the escrow rent, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
     
-    /// CHECK: This is a token account owned by the buyer to receive the NFT
-    #[account(mut)]
-    pub buyer_token_account: UncheckedAccount<'info>,
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Escrow-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Seller token account receiving the NFT back on a refund
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Buyer token account receiving the NFT on release
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Option<Box<Account<'info, TokenAccount>>>,
     
     /// CHECK: Platform treasury
-    #[account(mut)]
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
     pub platform_treasury: UncheckedAccount<'info>,
     
     #[account(mut)]
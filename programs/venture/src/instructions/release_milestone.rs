@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{ESCROW_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::release_escrow::release_escrow_nft;
+use crate::states::{Escrow, EscrowStatus, Property, MarketplaceState};
+
+pub fn release_milestone(
+    ctx: Context<ReleaseMilestoneCtx>,
+    escrow_id: u64,
+    milestone_index: u8,
+) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let property = &mut ctx.accounts.property;
+    let state = &ctx.accounts.marketplace_state;
+    let authority = &ctx.accounts.authority;
+    let index = milestone_index as usize;
+
+    // Validations
+    if escrow.escrow_id != escrow_id {
+        return Err(ErrorCode::EscrowNotFound.into());
+    }
+    if escrow.is_completed {
+        return Err(ErrorCode::EscrowAlreadyCompleted.into());
+    }
+    if escrow.status == EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+    if Clock::get()?.unix_timestamp as u64 > escrow.closing_deadline {
+        return Err(ErrorCode::EscrowExpired.into());
+    }
+
+    // Only the buyer can release a milestone to the seller
+    if authority.key() != escrow.buyer {
+        return Err(ErrorCode::NotAuthorizedToReleaseEscrow.into());
+    }
+
+    // Milestones are released in order
+    let next_index = escrow.milestones.iter().position(|milestone| milestone.status == 0);
+    if next_index != Some(index) {
+        return Err(ErrorCode::MilestoneNotReleasable.into());
+    }
+    let release_amount = escrow.milestones[index].amount;
+    if escrow.deposited_amount < release_amount {
+        return Err(ErrorCode::InsufficientEscrowBalance.into());
+    }
+
+    let platform_fee = (release_amount * state.platform_fee) / 10000;
+    let net_amount = release_amount - platform_fee;
+
+    // Release the tranche to the seller
+    **escrow.to_account_info().try_borrow_mut_lamports()? -= release_amount;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += net_amount;
+    **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
+
+    escrow.deposited_amount -= release_amount;
+    escrow.milestones[index].status = 1;
+    msg!("Milestone {} of escrow {} released: {} lamports", index, escrow_id, release_amount);
+
+    if index + 1 < escrow.milestones.len() {
+        return Ok(());
+    }
+
+    // The final milestone delivers the NFT from custody to the buyer
+    let buyer_token_account = ctx
+        .accounts
+        .buyer_token_account
+        .as_ref()
+        .ok_or(ErrorCode::InvalidBidderAccount)?;
+    release_escrow_nft(
+        escrow,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &buyer_token_account.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.bumps.escrow,
+    )?;
+
+    // Anything deposited beyond the milestones goes back to the buyer
+    let excess = escrow.deposited_amount;
+    **escrow.to_account_info().try_borrow_mut_lamports()? -= excess;
+    **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += excess;
+
+    // Update property ownership
+    property.owner = escrow.buyer;
+    property.is_listed = false;
+    property.active_escrow_id = None;
+
+    escrow.is_completed = true;
+    escrow.released_to_seller = true;
+    escrow.deposited_amount = 0;
+    escrow.close(ctx.accounts.rent_payer.to_account_info())?;
+    msg!("Final milestone released. Property transferred to buyer.");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct ReleaseMilestoneCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            escrow_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            escrow.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    /// CHECK: Buyer receiving any excess deposit, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.buyer
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// CHECK: Seller receiving the tranche, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.seller
+    )]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// CHECK: Account that paid the escrow rent, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Escrow-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Buyer token account receiving the NFT, required for the final milestone
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// CHECK: Platform treasury
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
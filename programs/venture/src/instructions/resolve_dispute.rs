@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{ESCROW_SEED, MARKETPLACE_STATE_SEED, PROPERTY_SEED};
+use crate::errors::ErrorCode;
+use crate::instructions::release_escrow::release_escrow_nft;
+use crate::states::{Escrow, EscrowStatus, Property, MarketplaceState};
+
+pub fn resolve_dispute(
+    ctx: Context<ResolveDisputeCtx>,
+    escrow_id: u64,
+    seller_bps: u64,
+    transfer_property: bool,
+) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let property = &mut ctx.accounts.property;
+    let state = &ctx.accounts.marketplace_state;
+
+    // Validations
+    if escrow.escrow_id != escrow_id {
+        return Err(ErrorCode::EscrowNotFound.into());
+    }
+    if escrow.status != EscrowStatus::Disputed {
+        return Err(ErrorCode::EscrowNotDisputed.into());
+    }
+    if ctx.accounts.arbiter.key() != escrow.arbiter {
+        return Err(ErrorCode::NotEscrowArbiter.into());
+    }
+    if seller_bps > 10000 {
+        return Err(ErrorCode::InvalidDisputeRuling.into());
+    }
+
+    // Split what is left in the escrow, the platform fee only applies to the seller's share
+    let held_amount = escrow.deposited_amount;
+    let seller_share = (held_amount * seller_bps) / 10000;
+    let platform_fee = (seller_share * state.platform_fee) / 10000;
+    let buyer_share = held_amount - seller_share;
+
+    **escrow.to_account_info().try_borrow_mut_lamports()? -= held_amount;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += seller_share - platform_fee;
+    **ctx.accounts.platform_treasury.to_account_info().try_borrow_mut_lamports()? += platform_fee;
+    **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += buyer_share;
+
+    // The NFT leaves custody to the buyer or back to the seller, as ruled
+    let recipient = if transfer_property {
+        ctx.accounts.buyer_token_account.as_ref()
+    } else {
+        ctx.accounts.seller_token_account.as_ref()
+    }
+    .ok_or(ErrorCode::InvalidBidderAccount)?;
+    release_escrow_nft(
+        escrow,
+        &ctx.accounts.nft_vault.to_account_info(),
+        &recipient.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.bumps.escrow,
+    )?;
+
+    if transfer_property {
+        // Update property ownership
+        property.owner = escrow.buyer;
+        property.is_listed = false;
+    }
+    property.active_escrow_id = None;
+
+    escrow.status = EscrowStatus::Resolved;
+    escrow.is_completed = true;
+    escrow.released_to_seller = transfer_property;
+    escrow.deposited_amount = 0;
+
+    msg!(
+        "Dispute on escrow {} resolved: {} lamports to seller, {} to buyer, property transferred: {}",
+        escrow_id,
+        seller_share,
+        buyer_share,
+        transfer_property
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct ResolveDisputeCtx<'info> {
+    #[account(
+        seeds = [MARKETPLACE_STATE_SEED],
+        bump
+    )]
+    pub marketplace_state: Account<'info, MarketplaceState>,
+    
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            ESCROW_SEED,
+            escrow_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PROPERTY_SEED,
+            escrow.property_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub property: Account<'info, Property>,
+    
+    /// CHECK: Buyer receiving their share, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.buyer
+    )]
+    pub buyer: UncheckedAccount<'info>,
+    
+    /// CHECK: Seller receiving their share, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.seller
+    )]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// CHECK: Account that paid the escrow rent, validated against the escrow
+    #[account(
+        mut,
+        address = escrow.rent_payer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+    
+    #[account(address = property.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Escrow-owned token account holding the NFT
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    /// Seller token account receiving the NFT back, required unless the property is transferred
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// Buyer token account receiving the NFT, required when the property is transferred
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    /// CHECK: Platform treasury
+    #[account(
+        mut,
+        address = marketplace_state.platform_treasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+    
+    pub arbiter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::{AUCTION_SEED, BID_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Auction, Bid};
+
+pub fn reveal_bid(
+    ctx: Context<RevealBidCtx>,
+    auction_id: u64,
+    _bid_index: u64,
+    amount: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let bid = &mut ctx.accounts.bid;
+    let bidder = &ctx.accounts.bidder;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // Validations
+    if auction.auction_id != auction_id || bid.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if !auction.is_sealed() {
+        return Err(ErrorCode::InvalidAuctionType.into());
+    }
+    if auction.is_ended || now <= auction.end_time || now > auction.reveal_end_time {
+        return Err(ErrorCode::NotInRevealPhase.into());
+    }
+    if bid.revealed {
+        return Err(ErrorCode::BidAlreadyRevealed.into());
+    }
+
+    // The commitment binds amount, salt and bidder so it can't be copied by others
+    let hash = hashv(&[
+        amount.to_le_bytes().as_ref(),
+        salt.as_ref(),
+        bidder.key().as_ref(),
+    ]);
+    if hash.to_bytes() != bid.commitment {
+        return Err(ErrorCode::InvalidBidCommitment.into());
+    }
+    if amount > bid.deposit {
+        return Err(ErrorCode::InsufficientBidDeposit.into());
+    }
+
+    bid.amount = amount;
+    bid.max_amount = amount;
+    bid.revealed = true;
+
+    // Ties go to whoever revealed first
+    if amount < auction.starting_price || amount <= auction.current_bid {
+        if amount >= auction.starting_price && amount > auction.second_bid {
+            auction.second_bid = amount;
+            auction.runner_up = Some(bidder.key());
+        }
+        msg!("Bid revealed at {} lamports", amount);
+        return Ok(());
+    }
+
+    // Take the lead from the previous highest revealed bid
+    if let Some(prev_bidder) = auction.highest_bidder {
+        let previous_bid = ctx
+            .accounts
+            .previous_bid
+            .as_mut()
+            .ok_or(ErrorCode::InvalidPreviousBidder)?;
+
+        if previous_bid.auction_id != auction_id
+            || previous_bid.bidder != prev_bidder
+            || !previous_bid.is_winning
+        {
+            return Err(ErrorCode::InvalidPreviousBidder.into());
+        }
+        previous_bid.is_winning = false;
+    }
+
+    auction.second_bid = auction.current_bid;
+    auction.runner_up = auction.highest_bidder;
+    auction.current_bid = amount;
+    auction.highest_bidder = Some(bidder.key());
+    bid.is_winning = true;
+
+    msg!("Bid revealed at {} lamports, now highest", amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64, bid_index: u64)]
+pub struct RevealBidCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BID_SEED,
+            bidder.key().as_ref(),
+            auction_id.to_le_bytes().as_ref(),
+            bid_index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub bid: Account<'info, Bid>,
+    
+    /// Bid record of the current highest revealed bid, required to take the lead from it
+    #[account(mut)]
+    pub previous_bid: Option<Account<'info, Bid>>,
+    
+    pub bidder: Signer<'info>,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::{AUCTION_SEED, RESERVE_REVEAL_WINDOW};
+use crate::errors::ErrorCode;
+use crate::states::Auction;
+
+pub fn reveal_reserve(
+    ctx: Context<RevealReserveCtx>,
+    auction_id: u64,
+    reserve_price: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // Validations
+    if auction.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if auction.seller != ctx.accounts.seller.key() {
+        return Err(ErrorCode::NotPropertyOwner.into());
+    }
+    if auction.is_ended {
+        return Err(ErrorCode::AuctionAlreadyEnded.into());
+    }
+    let Some(commitment) = auction.reserve_commitment else {
+        return Err(ErrorCode::InvalidReserveCommitment.into());
+    };
+    // Revealing while bids are open would let bidders anchor to the reserve again
+    if now < auction.settle_time() {
+        return Err(ErrorCode::AuctionNotEnded.into());
+    }
+    if now > auction.settle_time() + RESERVE_REVEAL_WINDOW {
+        return Err(ErrorCode::NotInRevealPhase.into());
+    }
+
+    let hash = hashv(&[
+        reserve_price.to_le_bytes().as_ref(),
+        salt.as_ref(),
+    ]);
+    if hash.to_bytes() != commitment {
+        return Err(ErrorCode::InvalidReserveCommitment.into());
+    }
+
+    // A reserve below the starting price is met by any bid, like a visible one
+    auction.reserve_price = reserve_price.max(auction.starting_price);
+    auction.reserve_commitment = None;
+
+    msg!("Reserve price of auction {} revealed at {}", auction_id, auction.reserve_price);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct RevealReserveCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    pub seller: Signer<'info>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::constants::{AUCTION_SEED, BID_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Auction, Bid};
+
+pub fn slash_unrevealed_bid(
+    ctx: Context<SlashUnrevealedBidCtx>,
+    auction_id: u64,
+    _bid_index: u64,
+) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let bid = &mut ctx.accounts.bid;
+
+    // Validations
+    if auction.auction_id != auction_id || bid.auction_id != auction_id {
+        return Err(ErrorCode::AuctionNotFound.into());
+    }
+    if !auction.is_sealed() {
+        return Err(ErrorCode::InvalidAuctionType.into());
+    }
+    if !auction.is_ended {
+        return Err(ErrorCode::AuctionNotEnded.into());
+    }
+    if bid.refunded {
+        return Err(ErrorCode::BidAlreadyRefunded.into());
+    }
+    if bid.revealed {
+        return Err(ErrorCode::BidAlreadyRevealed.into());
+    }
+
+    // Deposits left out of settlement are slashed to the seller here, the bid account
+    // rent goes back to the bidder on close
+    let slashed_amount = bid.deposit;
+    **auction.to_account_info().try_borrow_mut_lamports()? -= slashed_amount;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += slashed_amount;
+    bid.refunded = true;
+
+    msg!("Slashed unrevealed deposit of {} from {} to the seller", slashed_amount, bid.bidder);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64, bid_index: u64)]
+pub struct SlashUnrevealedBidCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_SEED,
+            auction_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [
+            BID_SEED,
+            bidder.key().as_ref(),
+            auction_id.to_le_bytes().as_ref(),
+            bid_index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub bid: Account<'info, Bid>,
+    
+    /// CHECK: Bidder who never revealed, receives the bid account rent
+    #[account(mut)]
+    pub bidder: UncheckedAccount<'info>,
+    
+    /// CHECK: Seller receiving the slashed deposit, validated against the auction
+    #[account(
+        mut,
+        address = auction.seller
+    )]
+    pub seller: UncheckedAccount<'info>,
+    
+    /// Anyone may slash an unrevealed deposit once the auction has settled
+    pub caller: Signer<'info>,
+}
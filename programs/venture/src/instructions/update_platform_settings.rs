@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::constants::MARKETPLACE_STATE_SEED;
+use crate::constants::{MARKETPLACE_STATE_SEED, MAX_APPROVED_MINTS};
 use crate::errors::ErrorCode;
 use crate::states::MarketplaceState;
 
@@ -7,6 +7,10 @@ pub fn update_platform_settings(
     ctx: Context<UpdatePlatformSettingsCtx>,
     new_fee: Option<u64>,
     new_treasury: Option<Pubkey>,
+    new_crank_reward_bps: Option<u64>,
+    new_cancel_penalty_bps: Option<u64>,
+    new_min_bid_increment: Option<u64>,
+    new_approved_mints: Option<Vec<Pubkey>>,
 ) -> Result<()> {
     let state = &mut ctx.accounts.marketplace_state;
     let admin = &ctx.accounts.admin;
@@ -31,6 +35,42 @@ pub fn update_platform_settings(
         msg!("Platform treasury updated to {}", treasury);
     }
 
+    // Update settlement crank reward if provided
+    if let Some(crank_reward_bps) = new_crank_reward_bps {
+        if crank_reward_bps > 10000 {
+            return Err(ErrorCode::InvalidEscrowAmount.into());
+        }
+        state.crank_reward_bps = crank_reward_bps;
+        msg!("Crank reward updated to {}", crank_reward_bps);
+    }
+
+    // Update auction cancellation penalty if provided
+    if let Some(cancel_penalty_bps) = new_cancel_penalty_bps {
+        if cancel_penalty_bps > 10000 {
+            return Err(ErrorCode::InvalidEscrowAmount.into());
+        }
+        state.cancel_penalty_bps = cancel_penalty_bps;
+        msg!("Cancel penalty updated to {}", cancel_penalty_bps);
+    }
+
+    // Update platform minimum bid increment if provided
+    if let Some(min_bid_increment) = new_min_bid_increment {
+        if min_bid_increment == 0 {
+            return Err(ErrorCode::InvalidBidIncrement.into());
+        }
+        state.min_bid_increment = min_bid_increment;
+        msg!("Minimum bid increment updated to {}", min_bid_increment);
+    }
+
+    // Replace the SPL mints auctions may be priced in if provided, running auctions keep theirs
+    if let Some(approved_mints) = new_approved_mints {
+        if approved_mints.len() > MAX_APPROVED_MINTS {
+            return Err(ErrorCode::TooManyApprovedMints.into());
+        }
+        msg!("Approved payment mints updated to {:?}", approved_mints);
+        state.approved_mints = approved_mints;
+    }
+
     Ok(())
 }
 
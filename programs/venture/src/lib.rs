@@ -8,6 +8,7 @@ pub mod instructions;
 pub mod states;
 
 use instructions::*;
+use states::{AuctionConfig, Milestone};
 
 declare_id!("8G9j36JgtL33qV4kJ7mW1QbnemPSxVB2Zyept7fnWLmx");
 
@@ -41,44 +42,83 @@ pub mod property_marketplace {
         ctx: Context<ListPropertyCtx>,
         property_id: u64,
         price: u64,
-        listing_type: u8, // 0: Direct Sale, 1: Auction
+        listing_type: u8, // 0: Direct Sale, 1: Auction, 2: Dutch auction
     ) -> Result<()> {
         instructions::list_property::list_property(ctx, property_id, price, listing_type)
     }
 
-    pub fn create_auction(
-        ctx: Context<CreateAuctionCtx>,
+    pub fn create_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateAuctionCtx<'info>>,
         property_id: u64,
-        starting_price: u64,
-        reserve_price: u64,
-        duration: u64,
+        config: AuctionConfig,
     ) -> Result<()> {
-        instructions::create_auction::create_auction(ctx, property_id, starting_price, reserve_price, duration)
+        instructions::create_auction::create_auction(ctx, property_id, config)
     }
 
     pub fn place_bid(
         ctx: Context<PlaceBidCtx>,
         auction_id: u64,
         bid_amount: u64,
+        commitment: Option<[u8; 32]>,
+        max_bid: Option<u64>,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::place_bid::place_bid(ctx, auction_id, bid_amount, commitment, max_bid, allowlist_proof)
+    }
+
+    pub fn reveal_bid(
+        ctx: Context<RevealBidCtx>,
+        auction_id: u64,
+        bid_index: u64,
+        amount: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_bid::reveal_bid(ctx, auction_id, bid_index, amount, salt)
+    }
+
+    pub fn reveal_reserve(
+        ctx: Context<RevealReserveCtx>,
+        auction_id: u64,
+        reserve_price: u64,
+        salt: [u8; 32],
     ) -> Result<()> {
-        instructions::place_bid::place_bid(ctx, auction_id, bid_amount)
+        instructions::reveal_reserve::reveal_reserve(ctx, auction_id, reserve_price, salt)
     }
 
-    pub fn end_auction(
-        ctx: Context<EndAuctionCtx>,
+    pub fn end_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EndAuctionCtx<'info>>,
         auction_id: u64,
     ) -> Result<()> {
         instructions::end_auction::end_auction(ctx, auction_id)
     }
 
+    pub fn pay_balance(
+        ctx: Context<PayBalanceCtx>,
+        auction_id: u64,
+    ) -> Result<()> {
+        instructions::pay_balance::pay_balance(ctx, auction_id)
+    }
+
+    pub fn forfeit_bond(
+        ctx: Context<ForfeitBondCtx>,
+        auction_id: u64,
+        offer_to_runner_up: bool,
+    ) -> Result<()> {
+        instructions::forfeit_bond::forfeit_bond(ctx, auction_id, offer_to_runner_up)
+    }
+
     pub fn create_escrow(
         ctx: Context<CreateEscrowCtx>,
         property_id: u64,
         buyer: Pubkey,
         amount: u64,
         conditions: String,
+        closing_deadline: u64,
+        milestones: Vec<Milestone>,
     ) -> Result<()> {
-        instructions::create_escrow::create_escrow(ctx, property_id, buyer, amount, conditions)
+        instructions::create_escrow::create_escrow(
+            ctx, property_id, buyer, amount, conditions, closing_deadline, milestones
+        )
     }
 
     pub fn deposit_escrow(
@@ -97,7 +137,61 @@ pub mod property_marketplace {
         instructions::release_escrow::release_escrow(ctx, escrow_id, release_to_seller)
     }
 
-     pub fn buy_direct(
+    pub fn buy_dutch(
+        ctx: Context<BuyDutchCtx>,
+        auction_id: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::buy_dutch::buy_dutch(ctx, auction_id, allowlist_proof)
+    }
+
+    pub fn buy_now(
+        ctx: Context<BuyNowCtx>,
+        auction_id: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::buy_now::buy_now(ctx, auction_id, allowlist_proof)
+    }
+
+    pub fn release_milestone(
+        ctx: Context<ReleaseMilestoneCtx>,
+        escrow_id: u64,
+        milestone_index: u8,
+    ) -> Result<()> {
+        instructions::release_milestone::release_milestone(ctx, escrow_id, milestone_index)
+    }
+
+    pub fn approve_escrow(
+        ctx: Context<ApproveEscrowCtx>,
+        escrow_id: u64,
+    ) -> Result<()> {
+        instructions::approve_escrow::approve_escrow(ctx, escrow_id)
+    }
+
+    pub fn raise_dispute(
+        ctx: Context<RaiseDisputeCtx>,
+        escrow_id: u64,
+    ) -> Result<()> {
+        instructions::raise_dispute::raise_dispute(ctx, escrow_id)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDisputeCtx>,
+        escrow_id: u64,
+        seller_bps: u64,
+        transfer_property: bool,
+    ) -> Result<()> {
+        instructions::resolve_dispute::resolve_dispute(ctx, escrow_id, seller_bps, transfer_property)
+    }
+
+    pub fn expire_escrow(
+        ctx: Context<ExpireEscrowCtx>,
+        escrow_id: u64,
+    ) -> Result<()> {
+        instructions::expire_escrow::expire_escrow(ctx, escrow_id)
+    }
+
+    pub fn buy_direct(
         ctx: Context<BuyDirectCtx>,
         property_id: u64,
     ) -> Result<()> {
@@ -111,11 +205,41 @@ pub mod property_marketplace {
         instructions::cancel_listing::cancel_listing(ctx, property_id)
     }
 
+    pub fn cancel_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelAuctionCtx<'info>>,
+        auction_id: u64,
+    ) -> Result<()> {
+        instructions::cancel_auction::cancel_auction(ctx, auction_id)
+    }
+
     pub fn update_platform_settings(
         ctx: Context<UpdatePlatformSettingsCtx>,
         new_fee: Option<u64>,
         new_treasury: Option<Pubkey>,
+        new_crank_reward_bps: Option<u64>,
+        new_cancel_penalty_bps: Option<u64>,
+        new_min_bid_increment: Option<u64>,
+        new_approved_mints: Option<Vec<Pubkey>>,
+    ) -> Result<()> {
+        instructions::update_platform_settings::update_platform_settings(
+            ctx, new_fee, new_treasury, new_crank_reward_bps, new_cancel_penalty_bps,
+            new_min_bid_increment, new_approved_mints
+        )
+    }
+
+    pub fn claim_bid_refund(
+        ctx: Context<ClaimBidRefundCtx>,
+        auction_id: u64,
+        bid_index: u64,
+    ) -> Result<()> {
+        instructions::claim_bid_refund::claim_bid_refund(ctx, auction_id, bid_index)
+    }
+
+    pub fn slash_unrevealed_bid(
+        ctx: Context<SlashUnrevealedBidCtx>,
+        auction_id: u64,
+        bid_index: u64,
     ) -> Result<()> {
-        instructions::update_platform_settings::update_platform_settings(ctx, new_fee, new_treasury)
+        instructions::slash_unrevealed_bid::slash_unrevealed_bid(ctx, auction_id, bid_index)
     }
 }
\ No newline at end of file
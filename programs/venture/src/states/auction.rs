@@ -1,18 +1,226 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::{MAX_AUCTION_EXTENSION, MAX_INCREMENT_TIERS, MAX_LOT_SIZE};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct IncrementTier {
+    pub threshold: u64, // applies once the current bid reaches this amount
+    pub increment: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum BidIncrement {
+    Fixed { amount: u64 },
+    BasisPoints { bps: u64 },
+    Tiered {
+        #[max_len(MAX_INCREMENT_TIERS)]
+        tiers: Vec<IncrementTier>,
+    },
+}
+
+impl BidIncrement {
+    /// Minimum raise over `current_bid` required by this rule.
+    pub fn min_increment(&self, current_bid: u64) -> u64 {
+        match self {
+            BidIncrement::Fixed { amount } => *amount,
+            BidIncrement::BasisPoints { bps } => (current_bid * bps) / 10000,
+            BidIncrement::Tiered { tiers } => tiers
+                .iter()
+                .rev()
+                .find(|tier| current_bid >= tier.threshold)
+                .map_or(0, |tier| tier.increment),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            BidIncrement::Fixed { amount } => *amount > 0,
+            BidIncrement::BasisPoints { bps } => *bps <= 10000,
+            BidIncrement::Tiered { tiers } => {
+                tiers.len() <= MAX_INCREMENT_TIERS
+                    && tiers.windows(2).all(|pair| pair[0].threshold < pair[1].threshold)
+            }
+        }
+    }
+}
+
+/// What happens to the property when an auction ends without meeting its reserve.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RelistFallback {
+    None,
+    DirectSale { price: u64 },
+    Reauction { reserve_price: u64, duration: u64 },
+}
+
+/// Terms an auction is created with, see `Auction` for what each one does.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuctionConfig {
+    pub starting_price: u64,
+    pub reserve_price: u64,
+    pub duration: u64,
+    pub extension_window: u64,
+    pub extension_duration: u64,
+    pub auction_type: u8, // 0: English, 1: Sealed bid, 2: Dutch, 3: Vickrey
+    pub reveal_duration: u64,
+    pub price_step: u64,
+    pub buy_now_price: Option<u64>,
+    pub bid_increment: BidIncrement,
+    pub start_time: Option<u64>, // starts right away when None
+    pub allowlist_root: Option<[u8; 32]>,
+    pub bond_bps: u64,
+    pub payment_window: u64,
+    pub escrow_conditions: Option<String>,
+    pub lot_property_ids: Vec<u64>,
+    pub reserve_commitment: Option<[u8; 32]>,
+    pub relist_fallback: RelistFallback,
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct Auction {
     pub auction_id: u64,
     pub property_id: u64,
+    pub auction_type: u8, // 0: English, 1: Sealed bid, 2: Dutch, 3: Vickrey (sealed, second price)
     pub seller: Pubkey,
     pub starting_price: u64,
     pub reserve_price: u64,
     pub current_bid: u64,
+    pub second_bid: u64, // second-highest amount, the price paid in Vickrey auctions
     pub highest_bidder: Option<Pubkey>,
     pub bid_count: u64,
+    pub pending_refunds: u64, // outbid deposits still waiting for claim_bid_refund
     pub start_time: u64,
     pub end_time: u64,
+    pub reveal_end_time: u64, // sealed bids: end of the reveal phase that follows end_time
     pub is_ended: bool,
+    pub is_cancelled: bool,
     pub winner: Option<Pubkey>,
+    pub extension_window: u64, // bids within this many seconds of end_time extend the auction
+    pub extension_duration: u64,
+    pub total_extension: u64,
+    pub price_step: u64, // Dutch: seconds between price drops, 0 for a linear decay
+    pub buy_now_price: Option<u64>,
+    pub bid_increment: BidIncrement,
+    pub allowlist_root: Option<[u8; 32]>, // Merkle root of invited bidders for private auctions
+    pub bond_bps: u64, // bid bond as a share of the bid, 0 escrows the full amount
+    pub payment_window: u64, // bond auctions: seconds the winner has to pay the balance
+    pub payment_deadline: u64,
+    pub balance_paid: bool,
+    pub bond_forfeited: bool,
+    pub runner_up: Option<Pubkey>,
+    #[max_len(256)]
+    pub escrow_conditions: Option<String>, // settle into an escrow with these conditions instead of an instant sale
+    #[max_len(MAX_LOT_SIZE)]
+    pub lot_property_ids: Vec<u64>, // further properties sold together with property_id as one lot
+    pub payment_mint: Option<Pubkey>, // SPL mint bids are paid in, SOL when None
+    pub reserve_commitment: Option<[u8; 32]>, // hash(reserve_price, salt) of a hidden reserve until revealed
+    pub relist_fallback: RelistFallback, // applied when the reserve is not met
+}
+
+impl Auction {
+    /// Sealed auctions take committed bids that are revealed after `end_time`.
+    pub fn is_sealed(&self) -> bool {
+        self.auction_type == 1 || self.auction_type == 3
+    }
+
+    /// Starts a new bidding round on the same account after the reserve was not met.
+    /// `bid_count` keeps counting so bid accounts from earlier rounds never collide.
+    pub fn restart(&mut self, now: u64, reserve_price: u64, duration: u64) {
+        self.reserve_price = reserve_price;
+        self.reserve_commitment = None;
+        self.current_bid = 0;
+        self.second_bid = 0;
+        self.highest_bidder = None;
+        self.runner_up = None;
+        self.winner = None;
+        self.start_time = now;
+        self.end_time = now + duration;
+        self.reveal_end_time = self.end_time;
+        self.total_extension = 0;
+        self.is_ended = false;
+        self.relist_fallback = RelistFallback::None;
+    }
+
+    /// Earliest time the auction can be settled, after the reveal phase for sealed-bid auctions.
+    pub fn settle_time(&self) -> u64 {
+        if self.is_sealed() {
+            self.reveal_end_time
+        } else {
+            self.end_time
+        }
+    }
+
+    /// Checks a Merkle proof of `bidder` against the allowlist root, if the auction has one.
+    /// Leaves are `hash(bidder)` and each level hashes the sorted pair of nodes.
+    pub fn is_allowlisted(&self, bidder: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        let Some(root) = self.allowlist_root else {
+            return true;
+        };
+
+        let mut node = hashv(&[bidder.as_ref()]).to_bytes();
+        for sibling in proof {
+            node = if node <= *sibling {
+                hashv(&[node.as_ref(), sibling.as_ref()]).to_bytes()
+            } else {
+                hashv(&[sibling.as_ref(), node.as_ref()]).to_bytes()
+            };
+        }
+        node == root
+    }
+
+    /// Lamports a bid of `amount` has to escrow: its bond in bond auctions, the full amount otherwise.
+    pub fn escrow_for(&self, amount: u64) -> u64 {
+        if self.bond_bps > 0 {
+            (amount * self.bond_bps) / 10000
+        } else {
+            amount
+        }
+    }
+
+    /// Lamports of the leading bid held by the auction.
+    pub fn leading_escrow(&self) -> u64 {
+        self.escrow_for(self.current_bid)
+    }
+
+    /// Smallest raise over `amount` under this auction's rule and the platform minimum.
+    /// The platform minimum is in lamports, so token auctions only require some raise.
+    /// A bid always has to raise by at least 1, so it can never tie the leader.
+    pub fn min_increment(&self, amount: u64, platform_min: u64) -> u64 {
+        let platform_min = if self.payment_mint.is_some() { 1 } else { platform_min.max(1) };
+        self.bid_increment.min_increment(amount).max(platform_min)
+    }
+
+    /// Pushes `end_time` back when a bid lands inside the anti-sniping window.
+    pub fn extend_for_bid(&mut self, now: u64) {
+        if self.extension_window == 0 || self.end_time - now > self.extension_window {
+            return;
+        }
+
+        let extension = self
+            .extension_duration
+            .min(MAX_AUCTION_EXTENSION - self.total_extension);
+        self.end_time += extension;
+        self.total_extension += extension;
+        if extension > 0 {
+            msg!("Auction {} extended to {}", self.auction_id, self.end_time);
+        }
+    }
+
+    /// Current Dutch auction price, decaying from `starting_price` to `reserve_price`
+    /// between `start_time` and `end_time`.
+    pub fn dutch_price(&self, now: u64) -> u64 {
+        let duration = self.end_time - self.start_time;
+        if duration == 0 {
+            return self.reserve_price;
+        }
+
+        let mut elapsed = now.saturating_sub(self.start_time).min(duration);
+        if self.price_step > 0 {
+            elapsed -= elapsed % self.price_step;
+        }
+
+        let price_drop = (self.starting_price - self.reserve_price) as u128 * elapsed as u128
+            / duration as u128;
+        self.starting_price - price_drop as u64
+    }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::states::Auction;
 
 #[account]
 #[derive(InitSpace)]
@@ -6,6 +7,23 @@ pub struct Bid {
     pub auction_id: u64,
     pub bidder: Pubkey,
     pub amount: u64,
+    pub max_amount: u64, // ceiling of a proxy bid, equals amount otherwise
+    pub deposit: u64, // lamports escrowed in the auction for this bid
+    pub commitment: [u8; 32], // hash(amount, salt, bidder) for sealed bids
+    pub revealed: bool,
     pub timestamp: u64,
     pub is_winning: bool,
+    pub refunded: bool,
+}
+
+impl Bid {
+    /// Lamports still owed back to the bidder once the auction has ended.
+    /// The winning bid keeps what it owes for `current_bid` in the auction for the seller.
+    pub fn refundable_amount(&self, auction: &Auction) -> u64 {
+        if self.is_winning {
+            self.deposit.saturating_sub(auction.leading_escrow())
+        } else {
+            self.deposit
+        }
+    }
 }
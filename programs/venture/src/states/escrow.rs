@@ -1,4 +1,22 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_MILESTONES;
+
+/// Pending until the full amount is deposited. A dispute freezes the escrow until the arbiter resolves it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EscrowStatus {
+    Pending,
+    Funded,
+    Disputed,
+    Resolved,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Milestone {
+    pub description_hash: [u8; 32], // hash of the off-chain milestone description
+    pub amount: u64,
+    pub due_date: u64,
+    pub status: u8, // 0: Pending, 1: Released
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -12,6 +30,14 @@ pub struct Escrow {
     #[max_len(256)]
     pub conditions: String,
     pub created_at: u64,
+    pub closing_deadline: u64, // after this anyone can expire the escrow and refund the buyer
     pub is_completed: bool,
     pub released_to_seller: bool,
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>, // tranches released in order, empty for a single release
+    pub status: EscrowStatus,
+    pub arbiter: Pubkey, // the only one who can resolve a dispute
+    pub buyer_approved: bool, // buyer consents to releasing the funds to the seller
+    pub seller_approved: bool, // seller consents to refunding the buyer
+    pub rent_payer: Pubkey, // gets the escrow and NFT vault rent back once the escrow completes
 }
\ No newline at end of file
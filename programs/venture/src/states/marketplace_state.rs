@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_APPROVED_MINTS;
 
 #[account]
 #[derive(InitSpace)]
@@ -10,4 +11,9 @@ pub struct MarketplaceState {
     pub platform_fee: u64, // in basis points
     pub platform_treasury: Pubkey,
     pub admin: Pubkey,
+    pub crank_reward_bps: u64, // share of the platform fee paid to whoever settles an auction
+    pub cancel_penalty_bps: u64, // share of the leading bid a seller pays to cancel an auction
+    pub min_bid_increment: u64, // floor for every auction's bid increment rule
+    #[max_len(MAX_APPROVED_MINTS)]
+    pub approved_mints: Vec<Pubkey>, // SPL mints auctions may be priced in besides SOL
 }
\ No newline at end of file
@@ -23,5 +23,7 @@ pub struct Property {
     pub created_at: u64,
     pub is_listed: bool,
     pub list_price: u64,
-    pub listing_type: u8, // 0: Direct Sale, 1: Auction
+    pub listing_type: u8, // 0: Direct Sale, 1: Auction, 2: Dutch auction
+    pub active_auction_id: Option<u64>, // auction currently holding the property
+    pub active_escrow_id: Option<u64>, // escrow currently settling the property
 }
\ No newline at end of file